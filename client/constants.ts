@@ -51,4 +51,12 @@ export enum Seed {
   DrawAccount = "draw",
   TicketAccount = "ticket",
   Metadata = "metadata",
+  HostAccount = "host",
+  RecoveryAccount = "recovery",
+  CampaignAccount = "campaign",
+  DistributorAccount = "distributor",
+  VoucherAccount = "voucher",
+  StreamAccount = "stream",
+  StreamVault = "stream_vault",
+  StreamMemberAccount = "stream_member",
 }
@@ -0,0 +1,12 @@
+import { PublicKey } from "@solana/web3.js";
+import BN from "bn.js";
+import { program, Seed } from "../../client/constants";
+
+export const findDistributorAccountAddress = (id: BN) => {
+  const [pda] = PublicKey.findProgramAddressSync(
+    [Buffer.from(Seed.DistributorAccount), id.toArrayLike(Buffer, "le", 8)],
+    program.programId
+  );
+
+  return pda;
+};
@@ -0,0 +1,11 @@
+import { PublicKey } from "@solana/web3.js";
+import { program, Seed } from "../../client/constants";
+
+export const findHostAccountAddress = () => {
+  const [pda] = PublicKey.findProgramAddressSync(
+    [Buffer.from(Seed.HostAccount)],
+    program.programId
+  );
+
+  return pda;
+};
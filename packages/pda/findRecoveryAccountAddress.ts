@@ -0,0 +1,11 @@
+import { PublicKey } from "@solana/web3.js";
+import { program, Seed } from "../../client/constants";
+
+export const findRecoveryAccountAddress = (owner: PublicKey) => {
+  const [pda] = PublicKey.findProgramAddressSync(
+    [Buffer.from(Seed.RecoveryAccount), owner.toBuffer()],
+    program.programId
+  );
+
+  return pda;
+};
@@ -0,0 +1,37 @@
+import { PublicKey } from "@solana/web3.js";
+import BN from "bn.js";
+import { program, Seed } from "../../client/constants";
+
+export const findStreamAccountAddress = (id: BN) => {
+  const [pda] = PublicKey.findProgramAddressSync(
+    [Buffer.from(Seed.StreamAccount), id.toArrayLike(Buffer, "le", 8)],
+    program.programId
+  );
+
+  return pda;
+};
+
+export const findStreamVaultAddress = (stream: PublicKey) => {
+  const [pda] = PublicKey.findProgramAddressSync(
+    [Buffer.from(Seed.StreamVault), stream.toBuffer()],
+    program.programId
+  );
+
+  return pda;
+};
+
+export const findStreamMemberAddress = (
+  stream: PublicKey,
+  owner: PublicKey
+) => {
+  const [pda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from(Seed.StreamMemberAccount),
+      stream.toBuffer(),
+      owner.toBuffer(),
+    ],
+    program.programId
+  );
+
+  return pda;
+};
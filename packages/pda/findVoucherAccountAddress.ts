@@ -0,0 +1,16 @@
+import { PublicKey } from "@solana/web3.js";
+import BN from "bn.js";
+import { program, Seed } from "../../client/constants";
+
+export const findVoucherAccountAddress = (member: PublicKey, nonce: BN) => {
+  const [pda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from(Seed.VoucherAccount),
+      member.toBuffer(),
+      nonce.toArrayLike(Buffer, "le", 8),
+    ],
+    program.programId
+  );
+
+  return pda;
+};
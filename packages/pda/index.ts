@@ -1,6 +1,16 @@
+export { findCampaignAccountAddress } from "./findCampaignAccountAddress";
+export { findDistributorAccountAddress } from "./findDistributorAccountAddress";
 export { findDrawAccount } from "./findDrawAccountAddress";
+export { findHostAccountAddress } from "./findHostAccountAddress";
 export { findMetaplexAddress } from "./findMetaplexAddress";
+export { findRecoveryAccountAddress } from "./findRecoveryAccountAddress";
 export { findRewardTokenMintAddress } from "./findRewardTokenMintAddress";
+export {
+  findStreamAccountAddress,
+  findStreamMemberAddress,
+  findStreamVaultAddress,
+} from "./findStreamAccountAddress";
 export { findSubscriptionAccountAddress } from "./findSubscriptionAccountAddress";
 export { findTicketAccountAddress } from "./findTicketAccountAddress";
 export { findVaultAccountAddress } from "./findVaultAccountAddress";
+export { findVoucherAccountAddress } from "./findVoucherAccountAddress";
@@ -1,29 +1,47 @@
-import { Connection, Keypair, SystemProgram } from "@solana/web3.js";
+import {
+  Connection,
+  Keypair,
+  PublicKey,
+  SystemProgram,
+} from "@solana/web3.js";
 import { ENTRY_MINT_ADDRESS, program } from "../../client/constants";
 import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
   getAssociatedTokenAddressSync,
   TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
-import { findSubscriptionAccountAddress } from "@/pda";
+import { findHostAccountAddress, findSubscriptionAccountAddress } from "@/pda";
 
 export const claimRewards = async (connection: Connection, signer: Keypair) => {
   try {
     const subscriptionAccount = findSubscriptionAccountAddress(signer);
+    // Rewards are minted to the registered beneficiary, if any
+    const { beneficiary, referrer } =
+      await program.account.subscriptionAccount.fetch(subscriptionAccount);
+    const recipient = beneficiary.address.equals(PublicKey.default)
+      ? signer.publicKey
+      : beneficiary.address;
 
     const rewardTokenAccount = getAssociatedTokenAddressSync(
       ENTRY_MINT_ADDRESS,
-      signer.publicKey
+      recipient
     );
 
     const claimTransaction = await program.methods
-      .claim()
+      .claim(null)
       .accounts({
         subscription: subscriptionAccount,
+        host: findHostAccountAddress(),
         mint: ENTRY_MINT_ADDRESS,
         destinationTokenAccount: rewardTokenAccount,
+        // Referred members credit their referrer a share
+        referrer: referrer.equals(PublicKey.default) ? null : referrer,
+        owner: signer.publicKey,
+        recipient,
         signer: signer.publicKey,
-        systemProgram: SystemProgram.programId, // TODO: Remove this
+        systemProgram: SystemProgram.programId,
         tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
       })
       .signers([signer])
       .rpc();
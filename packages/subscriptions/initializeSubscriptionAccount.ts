@@ -1,23 +1,20 @@
-import { findSubscriptionAccountAddress, findVaultAccountAddress } from "@/pda";
+import { findHostAccountAddress, findSubscriptionAccountAddress } from "@/pda";
 import { Keypair, SystemProgram } from "@solana/web3.js";
-import { MINT_ADDRESS, program } from "../../client/constants";
-import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { program } from "../../client/constants";
 import { Logger } from "@/tools/Logger";
 
 export const initializeSubscriptionAccount = async (signer: Keypair) => {
   try {
-    const subscriptionAccount = findSubscriptionAccountAddress(signer),
-      vaultAccount = findVaultAccountAddress(MINT_ADDRESS, signer);
+    const subscriptionAccount = findSubscriptionAccountAddress(signer);
     // Initialize member account
     const initTransaction = await program.methods
       .initialize()
       .accounts({
         subscription: subscriptionAccount,
-        vaultTokenAccount: vaultAccount,
-        mint: MINT_ADDRESS,
+        host: findHostAccountAddress(),
+        referrer: null,
         signer: signer.publicKey,
-        systemProgram: SystemProgram.programId, // TODO: Remove this
-        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
       })
       .signers([signer])
       .rpc();
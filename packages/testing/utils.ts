@@ -3,6 +3,13 @@ import { MINT_ADDRESS, program } from "../../client/constants";
 import spl from "@solana/spl-token";
 import { Keypair, PublicKey } from "@solana/web3.js";
 import { readFileSync } from "fs";
+import assert from "assert";
+import {
+  findHostAccountAddress,
+  findRewardTokenMintAddress,
+  findSubscriptionAccountAddress,
+  findVaultAccountAddress,
+} from "@/pda";
 
 const tokenMint = MINT_ADDRESS;
 
@@ -70,3 +77,97 @@ export const mintTokenToAccount = async (
 
   await program.provider.connection.confirmTransaction(signature, "confirmed");
 };
+
+export const adminWallet = loadKeypairFileSync(
+  "./keys/DEV4MxokMrwCXpnJPjWREazY4sbw37fVPPaWtuf559Qp.json"
+);
+
+export const hostParams = (params: object = {}) => ({
+  activationReward: new BN(1),
+  rewardModel: 0,
+  emissionRate: new BN(0),
+  epochEmissionCap: new BN(0),
+  tierMultipliersBps: Array(5).fill(new BN(10_000)),
+  loyaltyBpsPerEpoch: new BN(0),
+  maxLoyaltyBps: new BN(0),
+  earlyAdopterLimit: new BN(0),
+  earlyAdopterMultiplierBps: new BN(10_000),
+  earlyAdopterPeriod: new BN(0),
+  vestingPeriod: new BN(0),
+  rewardExpiryEpochs: new BN(0),
+  voucherSigner: web3.PublicKey.default,
+  referralBps: new BN(0),
+  largeReleaseThreshold: new BN(0),
+  largeReleasePeriod: new BN(0),
+  releaseCollectionWindow: new BN(0),
+  ...params,
+});
+
+export const confirm = async (txHash: string) => {
+  await program.provider.connection.confirmTransaction(txHash, "confirmed");
+  return txHash;
+};
+
+export const createMember = async (amount: BN, member: Keypair) => {
+  await mintTokenToAccount(amount, member.publicKey, adminWallet);
+  const subscription = findSubscriptionAccountAddress(member);
+  const host = findHostAccountAddress();
+  // Initialize the subscription account
+  await program.methods
+    .initialize()
+    .accounts({
+      subscription,
+      host,
+      referrer: null,
+      signer: member.publicKey,
+      systemProgram: web3.SystemProgram.programId,
+    })
+    .signers([member])
+    .rpc()
+    .then(confirm);
+  // Lock the first deposit
+  await program.methods
+    .deposit(amount, null)
+    .accounts({
+      subscription,
+      host,
+      vaultTokenAccount: findVaultAccountAddress(tokenMint, member),
+      sourceTokenAccount: spl.getAssociatedTokenAddressSync(
+        tokenMint,
+        member.publicKey
+      ),
+      mint: tokenMint,
+      signer: member.publicKey,
+      tokenProgram: spl.TOKEN_PROGRAM_ID,
+      systemProgram: web3.SystemProgram.programId,
+    })
+    .signers([member])
+    .rpc()
+    .then(confirm);
+
+  return subscription;
+};
+
+export const claimAccounts = (
+  member: Keypair,
+  signer: PublicKey,
+  recipient: PublicKey = member.publicKey
+) => {
+  const mint = findRewardTokenMintAddress();
+  return {
+    subscription: findSubscriptionAccountAddress(member),
+    host: findHostAccountAddress(),
+    mint,
+    destinationTokenAccount: spl.getAssociatedTokenAddressSync(
+      mint,
+      recipient
+    ),
+    referrer: null,
+    owner: member.publicKey,
+    recipient,
+    signer,
+    tokenProgram: spl.TOKEN_PROGRAM_ID,
+    associatedTokenProgram: spl.ASSOCIATED_TOKEN_PROGRAM_ID,
+    systemProgram: web3.SystemProgram.programId,
+  };
+};
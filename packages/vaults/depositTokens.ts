@@ -1,6 +1,10 @@
 import BN from "bn.js";
 import { MINT_ADDRESS, MINT_DECIMALS, program } from "../../client/constants";
-import { findSubscriptionAccountAddress, findVaultAccountAddress } from "@/pda";
+import {
+  findHostAccountAddress,
+  findSubscriptionAccountAddress,
+  findVaultAccountAddress,
+} from "@/pda";
 import {
   getAssociatedTokenAddressSync,
   TOKEN_PROGRAM_ID,
@@ -22,9 +26,10 @@ export const depositTokens = async (amount: number, signer: Keypair) => {
     );
 
     const transaction = await program.methods
-      .deposit(amountToAdd)
+      .deposit(amountToAdd, null)
       .accounts({
         subscription: subscriptionAccount,
+        host: findHostAccountAddress(),
         vaultTokenAccount: vaultAccount,
         sourceTokenAccount: destinationTokenAccount,
         mint: MINT_ADDRESS,
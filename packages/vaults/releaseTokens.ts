@@ -1,6 +1,10 @@
 import BN from "bn.js";
 import { MINT_ADDRESS, program } from "../../client/constants";
-import { findSubscriptionAccountAddress, findVaultAccountAddress } from "@/pda";
+import {
+  findHostAccountAddress,
+  findSubscriptionAccountAddress,
+  findVaultAccountAddress,
+} from "@/pda";
 import {
   getAssociatedTokenAddressSync,
   TOKEN_PROGRAM_ID,
@@ -26,9 +30,10 @@ export const releaseTokens = async (amount: number, signer: Keypair) => {
       );
 
     const releaseTransaction = await program.methods
-      .release(withdrawAmount)
+      .release(withdrawAmount, null)
       .accounts({
         subscription: subscriptionAccount,
+        host: findHostAccountAddress(),
         vaultTokenAccount: vaultTokenAccount,
         sourceTokenAccount: destinationTokenAccount,
         mint: MINT_ADDRESS,
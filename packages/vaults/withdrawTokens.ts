@@ -1,4 +1,8 @@
-import { findSubscriptionAccountAddress, findVaultAccountAddress } from "@/pda";
+import {
+  findHostAccountAddress,
+  findSubscriptionAccountAddress,
+  findVaultAccountAddress,
+} from "@/pda";
 import { MINT_ADDRESS, program } from "../../client/constants";
 import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
 import {
@@ -21,11 +25,12 @@ export const withdrawTokens = async (signer: Keypair) => {
       );
 
     const withdrawTransaction = await program.methods
-      .withdraw()
+      .withdraw(null)
       .accounts({
         subscription: subscriptionAccount,
+        host: findHostAccountAddress(),
         vaultTokenAccount: vaultTokenAccount,
-        sourceTokenAccount: destinationTokenAccount,
+        destinationTokenAccount: destinationTokenAccount,
         mint: MINT_ADDRESS,
         signer: signer.publicKey,
         systemProgram: SystemProgram.programId,
@@ -15,9 +15,43 @@ pub const MATURATION_PERIOD: u64 = 2629800;
 pub const REWARD_FACTOR: u64 = 1000;
 /** Reward granted when member account is first activated */
 pub const ACTIVATION_REWARD: u64 = 0;
+/** Maximum recipients of a distribution, bounding the claimed bitmap to 8KB */
+pub const MAX_DISTRIBUTION_RECIPIENTS: usize = 65_536;
+/** Maximum number of guardians able to approve a recovery */
+pub const MAX_GUARDIANS: usize = 8;
+/** Number of membership tiers including the pending tier */
+pub const TOTAL_TIERS: usize = 5;
+/** Maximum number of epochs unclaimed rewards are tracked in */
+pub const MAX_REWARD_BUCKETS: usize = 12;
+/** Maximum number of campaigns open at once, bounding the accounts passed to members */
+pub const MAX_OPEN_CAMPAIGNS: u64 = 4;
+/** Maximum number of steps in the emission schedule */
+pub const MAX_SCHEDULE_STEPS: usize = 8;
+/** Weekly window the owner has to veto a pending recovery */
+pub const RECOVERY_DELAY: u64 = 604800;
+/** Delay before a changed withdrawal address takes effect */
+pub const WITHDRAWAL_ADDRESS_DELAY: u64 = 259200;
+/** Delay before a changed reward beneficiary takes effect */
+pub const BENEFICIARY_DELAY: u64 = 259200;
 
 pub const SUBSCRIPTION_SEED_PREFIX: &'static [u8] = b"subscription";
 
 pub const VAULT_SEED_PREFIX: &'static [u8] = b"vault";
 
 pub const REWARDS_SEED_PREFIX: &'static [u8] = b"reward";
+
+pub const RECOVERY_SEED_PREFIX: &[u8] = b"recovery";
+
+pub const HOST_SEED_PREFIX: &[u8] = b"host";
+
+pub const CAMPAIGN_SEED_PREFIX: &[u8] = b"campaign";
+
+pub const DISTRIBUTOR_SEED_PREFIX: &[u8] = b"distributor";
+
+pub const VOUCHER_SEED_PREFIX: &[u8] = b"voucher";
+
+pub const STREAM_SEED_PREFIX: &[u8] = b"stream";
+
+pub const STREAM_VAULT_SEED_PREFIX: &[u8] = b"stream_vault";
+
+pub const STREAM_MEMBER_SEED_PREFIX: &[u8] = b"stream_member";
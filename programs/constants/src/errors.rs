@@ -10,6 +10,8 @@ pub enum TransferError {
     InsufficientBalance,
     #[msg("Invalid mint")]
     InvalidMint,
+    #[msg("Destination is not the registered withdrawal address")]
+    InvalidDestination,
 }
 
 #[error_code]
@@ -20,6 +22,50 @@ pub enum MemberError {
     AccountDisabled,
     #[msg("Account status can't be changed")]
     AccountImmutable,
+    #[msg("Account changed since the expected sequence")]
+    StaleSequence,
+    #[msg("Signer is not authorized to claim for the member")]
+    UnauthorizedClaim,
+    #[msg("Referrer does not match the registered referrer")]
+    InvalidReferrer,
+    #[msg("Account already uses the latest schema version")]
+    AccountMigrated,
+}
+
+#[error_code]
+pub enum DistributionError {
+    #[msg("Invalid number of distribution recipients")]
+    InvalidRecipients,
+    #[msg("Recipient index out of bounds")]
+    InvalidIndex,
+    #[msg("Allocation already claimed")]
+    AlreadyClaimed,
+    #[msg("Invalid Merkle proof")]
+    InvalidProof,
+    #[msg("Claim exceeds the distribution allocation")]
+    ExceedsAllocation,
+}
+
+#[error_code]
+pub enum VoucherError {
+    #[msg("Vouchers are not enabled")]
+    VouchersDisabled,
+    #[msg("Voucher has expired")]
+    VoucherExpired,
+    #[msg("Invalid voucher signature")]
+    InvalidSignature,
+}
+
+#[error_code]
+pub enum StreamError {
+    #[msg("Invalid stream emission rate")]
+    InvalidEmissionRate,
+    #[msg("Insufficient stream funding")]
+    InsufficientFunding,
+    #[msg("No unallocated stream funding")]
+    NothingToReclaim,
+    #[msg("Signer is not the stream funder")]
+    InvalidFunder,
 }
 
 #[error_code]
@@ -32,6 +78,16 @@ pub enum LockingError {
     RewardsForbidden,
     #[msg("Insufficient lamport balance")]
     InsufficientFunds,
+    #[msg("Release can't be cancelled")]
+    ReleaseNotCancellable,
+}
+
+#[error_code]
+pub enum MathError {
+    #[msg("Arithmetic overflow")]
+    Overflow,
+    #[msg("Division by zero")]
+    DivisionByZero,
 }
 
 #[error_code]
@@ -46,4 +102,44 @@ pub enum HostError {
     AlreadyPaused,
     #[msg("System Not Paused")]
     NotPaused,
+    #[msg("Invalid emission schedule")]
+    InvalidSchedule,
+    #[msg("Invalid campaign")]
+    InvalidCampaign,
+    #[msg("Campaign settlement period has not elapsed")]
+    CampaignActive,
+    #[msg("Too many open campaigns")]
+    MaxCampaignsExceeded,
+    #[msg("Every open campaign must be passed")]
+    MissingCampaigns,
+    #[msg("Invalid reward model")]
+    InvalidRewardModel,
+    #[msg("Reward multiplier exceeds the maximum")]
+    InvalidMultiplier,
+    #[msg("Reward expiry exceeds the tracked epochs")]
+    InvalidRewardExpiry,
+    #[msg("Referral share exceeds the claimed rewards")]
+    InvalidReferralShare,
+}
+
+#[error_code]
+pub enum RecoveryError {
+    #[msg("Invalid guardian keys")]
+    InvalidGuardians,
+    #[msg("Invalid approval threshold")]
+    InvalidThreshold,
+    #[msg("Signer is not a guardian")]
+    UnknownGuardian,
+    #[msg("Recovery already pending")]
+    RecoveryPending,
+    #[msg("No recovery pending")]
+    NoRecoveryPending,
+    #[msg("Recovery owner mismatch")]
+    OwnerMismatch,
+    #[msg("Insufficient guardian approvals")]
+    InsufficientApprovals,
+    #[msg("Recovery veto window has not elapsed")]
+    RecoveryLocked,
+    #[msg("Subscription was not recovered")]
+    NotRecovered,
 }
@@ -1,3 +1,7 @@
+pub use recovery::*;
 pub use system::*;
+pub use transfers::*;
 
+pub mod recovery;
 pub mod system;
+pub mod transfers;
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct RecoveryProposedEvent {
+    pub owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub guardian: Pubkey,
+}
+
+#[event]
+pub struct RecoveryApprovedEvent {
+    pub owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub guardian: Pubkey,
+}
+
+#[event]
+pub struct RecoveryVetoedEvent {
+    pub owner: Pubkey,
+    pub new_owner: Pubkey,
+}
+
+#[event]
+pub struct RecoveryExecutedEvent {
+    pub owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct StreamMemberMigratedEvent {
+    pub stream: Pubkey,
+    pub owner: Pubkey,
+    pub new_owner: Pubkey,
+}
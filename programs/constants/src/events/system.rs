@@ -1,3 +1,5 @@
+use anchor_lang::prelude::*;
+
 #[event]
 pub struct EmergencyPauseEvent {
     pub state: Pubkey,
@@ -7,3 +9,24 @@ pub struct EmergencyPauseEvent {
 pub struct ResumeEvent {
     pub state: Pubkey,
 }
+
+#[event]
+pub struct CampaignCreatedEvent {
+    pub id: u64,
+    pub time_start: u64,
+    pub time_end: u64,
+    pub multiplier_bps: u64,
+}
+
+#[event]
+pub struct CampaignClosedEvent {
+    pub id: u64,
+}
+
+#[event]
+pub struct DistributionCreatedEvent {
+    pub id: u64,
+    pub root: [u8; 32],
+    pub total_amount: u64,
+    pub total_recipients: u32,
+}
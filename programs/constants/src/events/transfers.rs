@@ -1,4 +1,4 @@
-use anchor_lan::prelude::*;
+use anchor_lang::prelude::*;
 
 #[event]
 pub struct DepositEvent {
@@ -6,6 +6,75 @@ pub struct DepositEvent {
     pub amount: u64,
 }
 
+#[event]
+pub struct ActivationEvent {
+    pub sender: Pubkey,
+    pub reward: u64,
+}
+
+#[event]
+pub struct RewardsVestingEvent {
+    pub sender: Pubkey,
+    pub amount: u64,
+    pub time_vesting_end: u64,
+}
+
+#[event]
+pub struct VestingCollectedEvent {
+    pub sender: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct RewardsForfeitedEvent {
+    pub member_number: u64,
+    pub amount: u64,
+    pub epoch: u64,
+}
+
+#[event]
+pub struct DistributionClaimedEvent {
+    pub distributor: Pubkey,
+    pub sender: Pubkey,
+    pub index: u32,
+    pub amount: u64,
+}
+
+#[event]
+pub struct VoucherRedeemedEvent {
+    pub sender: Pubkey,
+    pub amount: u64,
+    pub nonce: u64,
+}
+
+#[event]
+pub struct StreamFundedEvent {
+    pub stream: Pubkey,
+    pub sender: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct StreamClaimedEvent {
+    pub stream: Pubkey,
+    pub sender: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct StreamReclaimedEvent {
+    pub stream: Pubkey,
+    pub funder: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ReferralRewardEvent {
+    pub referrer: Pubkey,
+    pub sender: Pubkey,
+    pub amount: u64,
+}
+
 #[event]
 pub struct WithdrawEvent {
     pub sender: Pubkey,
@@ -18,8 +87,55 @@ pub struct ReleaseEvent {
     pub amount: u64,
 }
 
+#[event]
+pub struct ReleaseDelayedEvent {
+    pub sender: Pubkey,
+    pub amount: u64,
+    pub time_released: u64,
+}
+
+#[event]
+pub struct ReleaseCancelledEvent {
+    pub sender: Pubkey,
+    pub amount: u64,
+}
+
 #[event]
 pub struct ClaimEvent {
     pub sender: Pubkey,
     pub amount: u64,
 }
+
+#[event]
+pub struct EmissionCapReachedEvent {
+    pub sender: Pubkey,
+    pub amount: u64,
+    pub deferred: u64,
+    pub time_epoch: u64,
+}
+
+#[event]
+pub struct WithdrawalAddressRequestedEvent {
+    pub owner: Pubkey,
+    pub destination: Pubkey,
+    pub time_effective: u64,
+}
+
+#[event]
+pub struct WithdrawalAddressChangedEvent {
+    pub owner: Pubkey,
+    pub destination: Pubkey,
+}
+
+#[event]
+pub struct BeneficiaryRequestedEvent {
+    pub owner: Pubkey,
+    pub beneficiary: Pubkey,
+    pub time_effective: u64,
+}
+
+#[event]
+pub struct BeneficiaryChangedEvent {
+    pub owner: Pubkey,
+    pub beneficiary: Pubkey,
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::{ADMIN_KEY, CAMPAIGN_SEED_PREFIX, HOST_SEED_PREFIX},
+    state::{campaign::CampaignAccount, host::HostAccount},
+};
+
+/**
+ Campaign addresses are derived from sequential identifiers,
+ so clients list campaigns by deriving every identifier below
+ the host `total_campaigns` counter.
+ */
+#[derive(Accounts)]
+pub struct CreateCampaignAccounts<'info> {
+    #[account(mut,
+        seeds = [HOST_SEED_PREFIX],
+        bump
+    )]
+    pub host: Account<'info, HostAccount>,
+
+    #[account(
+        init,
+        payer = admin,
+        seeds = [CAMPAIGN_SEED_PREFIX, host.total_campaigns.to_le_bytes().as_ref()],
+        bump,
+        space = CampaignAccount::space(),
+    )]
+    pub campaign: Account<'info, CampaignAccount>,
+
+    #[account(mut,
+        address = ADMIN_KEY
+    )]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseCampaignAccounts<'info> {
+    #[account(mut,
+        seeds = [HOST_SEED_PREFIX],
+        bump
+    )]
+    pub host: Account<'info, HostAccount>,
+
+    #[account(mut,
+        seeds = [CAMPAIGN_SEED_PREFIX, campaign.id.to_le_bytes().as_ref()],
+        bump,
+        close = admin
+    )]
+    pub campaign: Account<'info, CampaignAccount>,
+
+    #[account(mut,
+        address = ADMIN_KEY
+    )]
+    pub admin: Signer<'info>,
+}
@@ -1,34 +1,85 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, MintTo, Token, TokenAccount};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, MintTo, Token, TokenAccount},
+};
 
 use crate::{
-    constants::{REWARDS_SEED_PREFIX, SUBSCRIPTION_SEED_PREFIX},
-    state::subscription::SubscriptionAccount,
+    constants::{HOST_SEED_PREFIX, REWARDS_SEED_PREFIX, SUBSCRIPTION_SEED_PREFIX},
+    errors::MemberError,
+    state::{host::HostAccount, subscription::SubscriptionAccount},
 };
 
+/**
+ Claims may be signed by the owner, the approved claim
+ delegate, or anyone when permissionless claims are allowed,
+ so keepers can auto-claim. Only the owner may claim while
+ vesting is enabled, as claiming restarts the schedule.
+ Rewards are always minted to the reward mint associated
+ token account of the owner, or of the registered
+ beneficiary, which the signer creates if missing.
+ */
 #[derive(Accounts)]
 pub struct ClaimAccounts<'info> {
     #[account(mut,
-        seeds = [SUBSCRIPTION_SEED_PREFIX, signer.key().as_ref()],
-        bump
+        seeds = [SUBSCRIPTION_SEED_PREFIX, owner.key().as_ref()],
+        bump,
+        constraint = subscription.is_claim_authority(signer.key(), owner.key())
+            @ MemberError::UnauthorizedClaim
     )]
     pub subscription: Account<'info, SubscriptionAccount>,
 
+    #[account(mut,
+        seeds = [HOST_SEED_PREFIX],
+        bump
+    )]
+    pub host: Account<'info, HostAccount>,
+
     #[account(mut,
         seeds = [REWARDS_SEED_PREFIX],
         bump,
     )]
     pub mint: Account<'info, Mint>,
-    #[account(mut,
-        token::mint = mint
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = mint,
+        associated_token::authority = recipient,
     )]
     pub destination_token_account: Account<'info, TokenAccount>,
+
+    // Credited with the referral share, required when the member was referred
+    #[account(mut,
+        constraint = subscription.is_referred_by(referrer) @ MemberError::InvalidReferrer
+    )]
+    pub referrer: Option<Account<'info, SubscriptionAccount>>,
+
+    /// CHECK: Member wallet deriving the subscription, authorized through the subscription
+    pub owner: UncheckedAccount<'info>,
+    /// CHECK: Owner or registered beneficiary wallet owning the destination
+    #[account(
+        address = subscription.reward_recipient(owner.key(), Clock::get()?.unix_timestamp as u64)
+    )]
+    pub recipient: UncheckedAccount<'info>,
     #[account(mut)]
     pub signer: Signer<'info>,
     pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
+/** Members may approve a claim delegate and mint claimed rewards to another wallet */
+#[derive(Accounts)]
+pub struct ClaimSettingsAccounts<'info> {
+    #[account(mut,
+        seeds = [SUBSCRIPTION_SEED_PREFIX, signer.key().as_ref()],
+        bump
+    )]
+    pub subscription: Account<'info, SubscriptionAccount>,
+
+    pub signer: Signer<'info>,
+}
+
 impl<'info> ClaimAccounts<'info> {
     pub fn initialize_mint_context(&self) -> CpiContext<'_, '_, '_, 'info, MintTo<'info>> {
         CpiContext::new(
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, MintTo, Token, TokenAccount},
+};
+use crate::{
+    constants::{ADMIN_KEY, DISTRIBUTOR_SEED_PREFIX, HOST_SEED_PREFIX, REWARDS_SEED_PREFIX},
+    state::{
+        distributor::{DistributionParams, DistributorAccount},
+        host::HostAccount,
+    },
+};
+
+#[derive(Accounts)]
+#[instruction(params: DistributionParams)]
+pub struct CreateDistributionAccounts<'info> {
+    #[account(mut,
+        seeds = [HOST_SEED_PREFIX],
+        bump
+    )]
+    pub host: Account<'info, HostAccount>,
+
+    #[account(
+        init,
+        payer = admin,
+        seeds = [DISTRIBUTOR_SEED_PREFIX, host.total_distributions.to_le_bytes().as_ref()],
+        bump,
+        space = DistributorAccount::space(params.total_recipients),
+    )]
+    pub distributor: Account<'info, DistributorAccount>,
+
+    #[account(mut,
+        address = ADMIN_KEY
+    )]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimDistributionAccounts<'info> {
+    #[account(mut,
+        seeds = [DISTRIBUTOR_SEED_PREFIX, distributor.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub distributor: Account<'info, DistributorAccount>,
+
+    #[account(mut,
+        seeds = [REWARDS_SEED_PREFIX],
+        bump,
+    )]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = mint,
+        associated_token::authority = signer,
+    )]
+    pub destination_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimDistributionAccounts<'info> {
+    pub fn initialize_mint_context(&self) -> CpiContext<'_, '_, '_, 'info, MintTo<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            MintTo {
+                authority: self.mint.to_account_info(),
+                to: self.destination_token_account.to_account_info(),
+                mint: self.mint.to_account_info(),
+            },
+        )
+    }
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use crate::{
+    constants::{ADMIN_KEY, HOST_SEED_PREFIX, MINT_KEY},
+    state::host::HostAccount,
+};
+
+#[derive(Accounts)]
+pub struct InitializeHostAccounts<'info> {
+    #[account(
+        init,
+        payer = admin,
+        seeds = [HOST_SEED_PREFIX],
+        bump,
+        space = HostAccount::space(),
+    )]
+    pub host: Account<'info, HostAccount>,
+    #[account(
+        address = MINT_KEY
+    )]
+    pub mint: Account<'info, Mint>,
+    #[account(mut,
+        address = ADMIN_KEY
+    )]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct EmissionScheduleAccounts<'info> {
+    #[account(mut,
+        seeds = [HOST_SEED_PREFIX],
+        bump
+    )]
+    pub host: Account<'info, HostAccount>,
+    #[account(
+        address = ADMIN_KEY
+    )]
+    pub admin: Signer<'info>,
+}
+
+/**
+ Read-only access to the host configuration, returning the
+ queried value through the transaction return data.
+ */
+#[derive(Accounts)]
+pub struct EmissionAccounts<'info> {
+    #[account(
+        seeds = [HOST_SEED_PREFIX],
+        bump
+    )]
+    pub host: Account<'info, HostAccount>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigureHostAccounts<'info> {
+    #[account(mut,
+        seeds = [HOST_SEED_PREFIX],
+        bump
+    )]
+    pub host: Account<'info, HostAccount>,
+    #[account(
+        address = ADMIN_KEY
+    )]
+    pub admin: Signer<'info>,
+}
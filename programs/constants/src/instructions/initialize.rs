@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::{
-    constants::{ANCHOR_DISCRIMINATOR_SIZE, SUBSCRIPTION_SEED_PREFIX},
-    state::subscription::SubscriptionAccount,
+    constants::{ANCHOR_DISCRIMINATOR_SIZE, HOST_SEED_PREFIX, SUBSCRIPTION_SEED_PREFIX},
+    state::{host::HostAccount, subscription::SubscriptionAccount},
 };
 
 #[derive(Accounts)]
@@ -14,6 +14,14 @@ pub struct InitializeAccounts<'info> {
         space = ANCHOR_DISCRIMINATOR_SIZE + std::mem::size_of::<SubscriptionAccount>(),
     )]
     pub subscription: Account<'info, SubscriptionAccount>,
+    #[account(mut,
+        seeds = [HOST_SEED_PREFIX],
+        bump
+    )]
+    pub host: Account<'info, HostAccount>,
+    // Optional subscription of the referring member, which must be active
+    #[account(mut)]
+    pub referrer: Option<Account<'info, SubscriptionAccount>>,
     #[account(mut)]
     signer: Signer<'info>,
     system_program: Program<'info, System>,
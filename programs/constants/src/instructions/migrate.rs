@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::{HOST_SEED_PREFIX, SUBSCRIPTION_SEED_PREFIX},
+    state::host::HostAccount,
+};
+
+/**
+ Rewrites a subscription created before the current schema
+ version into the latest layout, reallocating the account
+ with the rent difference paid by the owner.
+ */
+#[derive(Accounts)]
+pub struct MigrateSubscriptionAccounts<'info> {
+    /// CHECK: Legacy subscription deserialized by the migration, which checks its discriminator
+    #[account(mut,
+        seeds = [SUBSCRIPTION_SEED_PREFIX, signer.key().as_ref()],
+        bump,
+        owner = crate::ID
+    )]
+    pub subscription: UncheckedAccount<'info>,
+
+    #[account(mut,
+        seeds = [HOST_SEED_PREFIX],
+        bump
+    )]
+    pub host: Account<'info, HostAccount>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
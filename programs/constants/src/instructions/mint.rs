@@ -25,7 +25,7 @@ pub struct InitializeRewards<'info> {
         seeds = [REWARDS_SEED_PREFIX],
         bump,
         payer = admin,
-        mint::decimals = 4,
+        mint::decimals = params.decimals,
         mint::authority = mint.key(), // Grant program authority without additional PDA
         owner = token_program.key()
     )]
@@ -1,11 +1,31 @@
+pub use campaign::*;
 pub use claim::*;
+pub use distributor::*;
 pub use exclude::*;
+pub use host::*;
 pub use initialize::*;
+pub use migrate::*;
 pub use mint::*;
+pub use query::*;
+pub use recovery::*;
+pub use release::*;
+pub use stream::*;
 pub use transfer::*;
+pub use voucher::*;
+pub use withdraw::*;
 
+pub mod campaign;
 pub mod claim;
+pub mod distributor;
 pub mod exclude;
+pub mod host;
 pub mod initialize;
+pub mod migrate;
 pub mod mint;
+pub mod query;
+pub mod recovery;
+pub mod release;
+pub mod stream;
 pub mod transfer;
+pub mod voucher;
+pub mod withdraw;
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::HOST_SEED_PREFIX,
+    state::{host::HostAccount, subscription::SubscriptionAccount},
+};
+
+/**
+ Views over any member subscription, such as the loyalty
+ multiplier or a reward preview. Nothing is written, so the
+ owner doesn't need to sign and clients may simulate them.
+ */
+#[derive(Accounts)]
+pub struct MemberQueryAccounts<'info> {
+    pub subscription: Account<'info, SubscriptionAccount>,
+
+    #[account(
+        seeds = [HOST_SEED_PREFIX],
+        bump
+    )]
+    pub host: Account<'info, HostAccount>,
+}
@@ -0,0 +1,195 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{CloseAccount, Mint, Token, TokenAccount, Transfer};
+use crate::{
+    constants::{
+        MINT_KEY, RECOVERY_SEED_PREFIX, STREAM_MEMBER_SEED_PREFIX, STREAM_SEED_PREFIX,
+        SUBSCRIPTION_SEED_PREFIX, VAULT_SEED_PREFIX,
+    },
+    errors::RecoveryError,
+    state::{
+        recovery::RecoveryAccount,
+        stream::{StreamAccount, StreamMemberAccount},
+        subscription::SubscriptionAccount,
+    },
+};
+
+#[derive(Accounts)]
+pub struct GuardianAccounts<'info> {
+    #[account(
+        init_if_needed,
+        payer = signer,
+        seeds = [RECOVERY_SEED_PREFIX, signer.key().as_ref()],
+        bump,
+        space = RecoveryAccount::space(),
+    )]
+    pub recovery: Account<'info, RecoveryAccount>,
+
+    #[account(
+        seeds = [SUBSCRIPTION_SEED_PREFIX, signer.key().as_ref()],
+        bump
+    )]
+    pub subscription: Account<'info, SubscriptionAccount>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/**
+ Guardians propose and approve recovery of a subscription
+ to a new wallet. The owner may veto the request until the
+ `RECOVERY_DELAY` has elapsed since it was proposed.
+ */
+#[derive(Accounts)]
+pub struct ProposeRecoveryAccounts<'info> {
+    #[account(mut,
+        seeds = [RECOVERY_SEED_PREFIX, recovery.owner.as_ref()],
+        bump
+    )]
+    pub recovery: Account<'info, RecoveryAccount>,
+
+    pub guardian: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct VetoRecoveryAccounts<'info> {
+    #[account(mut,
+        seeds = [RECOVERY_SEED_PREFIX, signer.key().as_ref()],
+        bump
+    )]
+    pub recovery: Account<'info, RecoveryAccount>,
+
+    pub signer: Signer<'info>,
+}
+
+/**
+ Subscription and vault addresses are derived from the owner
+ wallet, so recovery migrates the member state and locked
+ tokens into accounts derived from the new owner and closes
+ the accounts bound to the lost key.
+ */
+#[derive(Accounts)]
+pub struct ExecuteRecoveryAccounts<'info> {
+    #[account(mut,
+        seeds = [RECOVERY_SEED_PREFIX, recovery.owner.as_ref()],
+        bump,
+        constraint = recovery.new_owner == new_owner.key() @ RecoveryError::OwnerMismatch,
+        close = new_owner
+    )]
+    pub recovery: Account<'info, RecoveryAccount>,
+
+    #[account(mut,
+        seeds = [SUBSCRIPTION_SEED_PREFIX, recovery.owner.as_ref()],
+        bump,
+        close = new_owner
+    )]
+    pub subscription: Account<'info, SubscriptionAccount>,
+
+    #[account(
+        init,
+        payer = new_owner,
+        seeds = [SUBSCRIPTION_SEED_PREFIX, new_owner.key().as_ref()],
+        bump,
+        space = subscription.get_packed_len(),
+    )]
+    pub new_subscription: Account<'info, SubscriptionAccount>,
+
+    #[account(mut,
+        seeds = [VAULT_SEED_PREFIX, mint.key().as_ref(), recovery.owner.as_ref()],
+        token::mint = mint,
+        token::authority = subscription,
+        bump
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = new_owner,
+        seeds = [VAULT_SEED_PREFIX, mint.key().as_ref(), new_owner.key().as_ref()],
+        token::mint = mint,
+        token::authority = new_subscription,
+        bump
+    )]
+    pub new_vault_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        address = MINT_KEY
+    )]
+    pub mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub new_owner: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/**
+ Stream checkpoints are derived from the owner wallet too,
+ and can't all be enumerated during recovery. The new owner
+ migrates each checkpoint of the previous owner recorded on
+ the recovered subscription afterwards.
+ */
+#[derive(Accounts)]
+pub struct MigrateStreamMemberAccounts<'info> {
+    #[account(
+        seeds = [STREAM_SEED_PREFIX, stream.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub stream: Account<'info, StreamAccount>,
+
+    #[account(
+        seeds = [SUBSCRIPTION_SEED_PREFIX, signer.key().as_ref()],
+        bump,
+        constraint = subscription.recovered_from != Pubkey::default()
+            @ RecoveryError::NotRecovered
+    )]
+    pub subscription: Account<'info, SubscriptionAccount>,
+
+    #[account(mut,
+        seeds = [
+            STREAM_MEMBER_SEED_PREFIX,
+            stream.key().as_ref(),
+            subscription.recovered_from.as_ref()
+        ],
+        bump,
+        close = signer
+    )]
+    pub stream_member: Account<'info, StreamMemberAccount>,
+
+    #[account(
+        init,
+        payer = signer,
+        seeds = [STREAM_MEMBER_SEED_PREFIX, stream.key().as_ref(), signer.key().as_ref()],
+        bump,
+        space = StreamMemberAccount::space(),
+    )]
+    pub new_stream_member: Account<'info, StreamMemberAccount>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ExecuteRecoveryAccounts<'info> {
+    pub fn initialize_migrate_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        // Construct vault migration transfer instruction
+        let transfer_instruction: Transfer = Transfer {
+            from: self.vault_token_account.to_account_info(),
+            to: self.new_vault_token_account.to_account_info(),
+            authority: self.subscription.to_account_info(),
+        };
+        // Initialize the transfer context
+        CpiContext::new(self.token_program.to_account_info(), transfer_instruction)
+    }
+
+    pub fn initialize_close_context(&self) -> CpiContext<'_, '_, '_, 'info, CloseAccount<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            CloseAccount {
+                account: self.vault_token_account.to_account_info(),
+                destination: self.new_owner.to_account_info(),
+                authority: self.subscription.to_account_info(),
+            },
+        )
+    }
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::{HOST_SEED_PREFIX, SUBSCRIPTION_SEED_PREFIX},
+    state::{host::HostAccount, subscription::SubscriptionAccount},
+};
+
+/**
+ Releases above the host `large_release_threshold` unbond
+ for an extended period, during which the member may cancel
+ the release and return the tokens to their locked balance.
+ This provides a circuit breaker for compromised wallets.
+ */
+#[derive(Accounts)]
+pub struct CancelReleaseAccounts<'info> {
+    #[account(mut,
+        seeds = [SUBSCRIPTION_SEED_PREFIX, signer.key().as_ref()],
+        bump
+    )]
+    pub subscription: Account<'info, SubscriptionAccount>,
+
+    #[account(mut,
+        seeds = [HOST_SEED_PREFIX],
+        bump
+    )]
+    pub host: Account<'info, HostAccount>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,205 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount, Transfer},
+};
+use crate::{
+    constants::{
+        HOST_SEED_PREFIX, STREAM_MEMBER_SEED_PREFIX, STREAM_SEED_PREFIX, STREAM_VAULT_SEED_PREFIX,
+        SUBSCRIPTION_SEED_PREFIX,
+    },
+    errors::StreamError,
+    state::{
+        host::HostAccount,
+        stream::{StreamAccount, StreamMemberAccount},
+        subscription::SubscriptionAccount,
+    },
+};
+
+#[derive(Accounts)]
+pub struct CreateStreamAccounts<'info> {
+    #[account(mut,
+        seeds = [HOST_SEED_PREFIX],
+        bump
+    )]
+    pub host: Account<'info, HostAccount>,
+
+    #[account(
+        init,
+        payer = funder,
+        seeds = [STREAM_SEED_PREFIX, host.total_streams.to_le_bytes().as_ref()],
+        bump,
+        space = StreamAccount::space(),
+    )]
+    pub stream: Account<'info, StreamAccount>,
+
+    #[account(
+        init,
+        payer = funder,
+        seeds = [STREAM_VAULT_SEED_PREFIX, stream.key().as_ref()],
+        token::mint = mint,
+        token::authority = stream,
+        bump
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FundStreamAccounts<'info> {
+    #[account(mut,
+        seeds = [STREAM_SEED_PREFIX, stream.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub stream: Account<'info, StreamAccount>,
+
+    #[account(mut,
+        seeds = [STREAM_VAULT_SEED_PREFIX, stream.key().as_ref()],
+        bump
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut,
+        token::mint = stream.mint
+    )]
+    pub source_token_account: Account<'info, TokenAccount>,
+
+    pub signer: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+/**
+ Members register with a stream on their first claim, from
+ which point their matured balance accrues streamed tokens.
+ Slots are matured before settling, so the remaining
+ accounts must include every open bonus campaign.
+ */
+#[derive(Accounts)]
+pub struct ClaimStreamAccounts<'info> {
+    #[account(mut,
+        seeds = [STREAM_SEED_PREFIX, stream.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub stream: Account<'info, StreamAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        seeds = [STREAM_MEMBER_SEED_PREFIX, stream.key().as_ref(), signer.key().as_ref()],
+        bump,
+        space = StreamMemberAccount::space(),
+    )]
+    pub stream_member: Account<'info, StreamMemberAccount>,
+
+    #[account(mut,
+        seeds = [SUBSCRIPTION_SEED_PREFIX, signer.key().as_ref()],
+        bump
+    )]
+    pub subscription: Account<'info, SubscriptionAccount>,
+
+    #[account(mut,
+        seeds = [HOST_SEED_PREFIX],
+        bump
+    )]
+    pub host: Account<'info, HostAccount>,
+
+    #[account(mut,
+        seeds = [STREAM_VAULT_SEED_PREFIX, stream.key().as_ref()],
+        bump
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = mint,
+        associated_token::authority = signer,
+    )]
+    pub destination_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        address = stream.mint
+    )]
+    pub mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/**
+ Returns emission forgone by members whose matured balance
+ shrank below their checkpoint to the stream funder, who
+ signs the reclaim.
+ */
+#[derive(Accounts)]
+pub struct ReclaimStreamAccounts<'info> {
+    #[account(mut,
+        seeds = [STREAM_SEED_PREFIX, stream.id.to_le_bytes().as_ref()],
+        bump,
+        has_one = funder @ StreamError::InvalidFunder
+    )]
+    pub stream: Account<'info, StreamAccount>,
+
+    #[account(mut,
+        seeds = [STREAM_VAULT_SEED_PREFIX, stream.key().as_ref()],
+        bump
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut,
+        token::mint = stream.mint,
+        token::authority = funder
+    )]
+    pub destination_token_account: Account<'info, TokenAccount>,
+
+    pub funder: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> FundStreamAccounts<'info> {
+    pub fn initialize_fund_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        // Construct funding transfer instruction
+        let transfer_instruction: Transfer = Transfer {
+            from: self.source_token_account.to_account_info(),
+            to: self.vault_token_account.to_account_info(),
+            authority: self.signer.to_account_info(),
+        };
+        // Initialize the transfer context
+        CpiContext::new(self.token_program.to_account_info(), transfer_instruction)
+    }
+}
+
+impl<'info> ClaimStreamAccounts<'info> {
+    pub fn initialize_claim_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        // Construct stream claim transfer instruction
+        let transfer_instruction: Transfer = Transfer {
+            from: self.vault_token_account.to_account_info(),
+            to: self.destination_token_account.to_account_info(),
+            authority: self.stream.to_account_info(),
+        };
+        // Initialize the transfer context
+        CpiContext::new(self.token_program.to_account_info(), transfer_instruction)
+    }
+}
+
+impl<'info> ReclaimStreamAccounts<'info> {
+    pub fn initialize_reclaim_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        // Construct stream reclaim transfer instruction
+        let transfer_instruction: Transfer = Transfer {
+            from: self.vault_token_account.to_account_info(),
+            to: self.destination_token_account.to_account_info(),
+            authority: self.stream.to_account_info(),
+        };
+        // Initialize the transfer context
+        CpiContext::new(self.token_program.to_account_info(), transfer_instruction)
+    }
+}
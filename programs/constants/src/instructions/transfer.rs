@@ -1,8 +1,8 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, Token, TokenAccount, Transfer};
 use crate::{
-    constants::{MINT_KEY, SUBSCRIPTION_SEED_PREFIX, VAULT_SEED_PREFIX},
-    state::subscription::SubscriptionAccount,
+    constants::{HOST_SEED_PREFIX, MINT_KEY, SUBSCRIPTION_SEED_PREFIX, VAULT_SEED_PREFIX},
+    state::{host::HostAccount, subscription::SubscriptionAccount},
 };
 
 #[derive(Accounts)]
@@ -13,6 +13,12 @@ pub struct TransferAccounts<'info> {
     )]
     pub subscription: Account<'info, SubscriptionAccount>,
 
+    #[account(mut,
+        seeds = [HOST_SEED_PREFIX],
+        bump
+    )]
+    pub host: Account<'info, HostAccount>,
+
     #[account(mut,
         seeds = [VAULT_SEED_PREFIX, mint.key().as_ref(), signer.key().as_ref()],
         token::mint = mint,
@@ -49,15 +55,4 @@ impl<'info> TransferAccounts<'info> {
         // Initialize the transfer context
         CpiContext::new(self.token_program.to_account_info(), transfer_instruction)
     }
-
-    pub fn initialize_withdraw_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
-        // Construct withdraw transfer instruction
-        let transfer_instruction: Transfer = Transfer {
-            from: self.vault_token_account.to_account_info(),
-            to: self.source_token_account.to_account_info(),
-            authority: self.subscription.to_account_info(),
-        };
-        // Initialize the transfer context
-        CpiContext::new(self.token_program.to_account_info(), transfer_instruction)
-    }
 }
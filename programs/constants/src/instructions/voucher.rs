@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+use crate::{
+    constants::{HOST_SEED_PREFIX, SUBSCRIPTION_SEED_PREFIX, VOUCHER_SEED_PREFIX},
+    state::{host::HostAccount, subscription::SubscriptionAccount, voucher::VoucherAccount},
+};
+
+/**
+ Vouchers are signed off-chain by the host `voucher_signer`
+ and verified by an Ed25519 program instruction placed
+ directly before the redemption in the same transaction.
+ The member funds the nonce record, which is never closed.
+ */
+#[derive(Accounts)]
+#[instruction(amount: u64, nonce: u64)]
+pub struct RedeemVoucherAccounts<'info> {
+    #[account(mut,
+        seeds = [SUBSCRIPTION_SEED_PREFIX, signer.key().as_ref()],
+        bump
+    )]
+    pub subscription: Account<'info, SubscriptionAccount>,
+
+    #[account(
+        seeds = [HOST_SEED_PREFIX],
+        bump
+    )]
+    pub host: Account<'info, HostAccount>,
+
+    #[account(
+        init,
+        payer = signer,
+        seeds = [VOUCHER_SEED_PREFIX, signer.key().as_ref(), nonce.to_le_bytes().as_ref()],
+        bump,
+        space = VoucherAccount::space(),
+    )]
+    pub voucher: Account<'info, VoucherAccount>,
+
+    /// CHECK: Instructions sysvar introspected for the Ed25519 verification
+    #[account(
+        address = sysvar::instructions::ID
+    )]
+    pub instructions: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
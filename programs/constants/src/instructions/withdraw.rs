@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount, Transfer};
+use crate::{
+    constants::{HOST_SEED_PREFIX, MINT_KEY, SUBSCRIPTION_SEED_PREFIX, VAULT_SEED_PREFIX},
+    state::{host::HostAccount, subscription::SubscriptionAccount},
+};
+
+#[derive(Accounts)]
+pub struct WithdrawAccounts<'info> {
+    #[account(mut,
+        seeds = [SUBSCRIPTION_SEED_PREFIX, signer.key().as_ref()],
+        bump
+    )]
+    pub subscription: Account<'info, SubscriptionAccount>,
+
+    #[account(mut,
+        seeds = [HOST_SEED_PREFIX],
+        bump
+    )]
+    pub host: Account<'info, HostAccount>,
+
+    #[account(mut,
+        seeds = [VAULT_SEED_PREFIX, mint.key().as_ref(), signer.key().as_ref()],
+        token::mint = mint,
+        token::authority = subscription,
+        bump
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    // Owner must match the registered withdrawal address
+    #[account(mut,
+        token::mint = mint
+    )]
+    pub destination_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        address = MINT_KEY
+    )]
+    pub mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> WithdrawAccounts<'info> {
+    pub fn initialize_withdraw_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        // Construct withdraw transfer instruction
+        let transfer_instruction: Transfer = Transfer {
+            from: self.vault_token_account.to_account_info(),
+            to: self.destination_token_account.to_account_info(),
+            authority: self.subscription.to_account_info(),
+        };
+        // Initialize the transfer context
+        CpiContext::new(self.token_program.to_account_info(), transfer_instruction)
+    }
+}
+
+/**
+ Members may register a fixed destination for withdrawn
+ tokens, such as a cold wallet.
+ */
+#[derive(Accounts)]
+pub struct WithdrawalAddressAccounts<'info> {
+    #[account(mut,
+        seeds = [SUBSCRIPTION_SEED_PREFIX, signer.key().as_ref()],
+        bump
+    )]
+    pub subscription: Account<'info, SubscriptionAccount>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+}
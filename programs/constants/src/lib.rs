@@ -4,13 +4,17 @@ use anchor_spl::token::{self, TokenAccount};
 
 use constants::*;
 use errors::*;
+use events::*;
 use instructions::*;
 use state::*;
 use utils::*;
 
 pub mod constants;
 pub mod errors;
+pub mod events;
 pub mod instructions;
+pub mod math;
+pub mod rewards;
 pub mod state;
 pub mod utils;
 
@@ -20,23 +24,375 @@ declare_id!("LFV1t2uUvpEZuhduXTepyimVJ35ZANUThNPH8yp1w7o");
 mod reward_program {
     use super::*;
 
+    pub fn initialize_host(ctx: Context<InitializeHostAccounts>, params: HostParams) -> Result<()> {
+        let time_now: i64 = Clock::get()?.unix_timestamp;
+        let host: &mut Account<HostAccount> = &mut ctx.accounts.host;
+        // Validate time before reassigning to u64
+        require!(time_now > 0, HostError::InvalidTimestamp);
+        // Start from the default host configuration
+        host.set_inner(HostAccount::default());
+        // Conversions follow the decimals of the locked token mint
+        host.mint_decimals = ctx.accounts.mint.decimals;
+        host.time_accrued = time_now as u64;
+        host.time_epoch = time_now as u64;
+        host.configure(params)?;
+
+        sol_log_compute_units();
+        Ok(())
+    }
+
+    pub fn configure_host(ctx: Context<ConfigureHostAccounts>, params: HostParams) -> Result<()> {
+        let time_now: i64 = Clock::get()?.unix_timestamp;
+        let host: &mut Account<HostAccount> = &mut ctx.accounts.host;
+        // Validate time before reassigning to u64
+        require!(time_now > 0, HostError::InvalidTimestamp);
+        // Settle global rewards under the previous model and rate
+        host.reward_model()?.update(host, time_now as u64)?;
+        host.configure(params)?;
+
+        sol_log_compute_units();
+        Ok(())
+    }
+
+    pub fn set_emission_schedule(
+        ctx: Context<EmissionScheduleAccounts>,
+        schedule: EmissionSchedule,
+    ) -> Result<()> {
+        let time_now: i64 = Clock::get()?.unix_timestamp;
+        let host: &mut Account<HostAccount> = &mut ctx.accounts.host;
+        // Validate time before reassigning to u64
+        require!(time_now > 0, HostError::InvalidTimestamp);
+        schedule.validate()?;
+        // Settle global rewards under the previous schedule
+        host.reward_model()?.update(host, time_now as u64)?;
+        host.schedule = schedule;
+
+        sol_log_compute_units();
+        Ok(())
+    }
+
+    pub fn remaining_emission(ctx: Context<EmissionAccounts>) -> Result<u64> {
+        let time_now: i64 = Clock::get()?.unix_timestamp;
+        // Validate time before reassigning to u64
+        require!(time_now > 0, HostError::InvalidTimestamp);
+
+        Ok(ctx.accounts.host.remaining_emission(time_now as u64))
+    }
+
+    pub fn create_campaign(
+        ctx: Context<CreateCampaignAccounts>,
+        params: CampaignParams,
+    ) -> Result<()> {
+        let time_now: i64 = Clock::get()?.unix_timestamp;
+        let host: &mut Account<HostAccount> = &mut ctx.accounts.host;
+        let campaign: &mut Account<CampaignAccount> = &mut ctx.accounts.campaign;
+        // Validate time before reassigning to u64
+        require!(time_now > 0, HostError::InvalidTimestamp);
+        require!(
+            host.open_campaigns < MAX_OPEN_CAMPAIGNS,
+            HostError::MaxCampaignsExceeded
+        );
+        // Assign the next sequential identifier
+        campaign.configure(host.total_campaigns, params, time_now as u64)?;
+        host.total_campaigns += 1;
+        host.open_campaigns += 1;
+
+        emit!(CampaignCreatedEvent {
+            id: campaign.id,
+            time_start: campaign.time_start,
+            time_end: campaign.time_end,
+            multiplier_bps: campaign.multiplier_bps,
+        });
+
+        sol_log_compute_units();
+        Ok(())
+    }
+
+    pub fn close_campaign(ctx: Context<CloseCampaignAccounts>) -> Result<()> {
+        let time_now: i64 = Clock::get()?.unix_timestamp;
+        // Validate time before reassigning to u64
+        require!(time_now > 0, HostError::InvalidTimestamp);
+        // Members may still settle rewards boosted by the campaign
+        require!(
+            ctx.accounts.campaign.is_closable(time_now as u64),
+            HostError::CampaignActive
+        );
+        ctx.accounts.host.open_campaigns = ctx
+            .accounts
+            .host
+            .open_campaigns
+            .checked_sub(1)
+            .ok_or(MathError::Overflow)?;
+
+        emit!(CampaignClosedEvent {
+            id: ctx.accounts.campaign.id,
+        });
+
+        sol_log_compute_units();
+        Ok(())
+    }
+
+    pub fn create_stream(ctx: Context<CreateStreamAccounts>, params: StreamParams) -> Result<()> {
+        let time_now: i64 = Clock::get()?.unix_timestamp;
+        let host: &mut Account<HostAccount> = &mut ctx.accounts.host;
+        let stream: &mut Account<StreamAccount> = &mut ctx.accounts.stream;
+        // Validate time before reassigning to u64
+        require!(time_now > 0, HostError::InvalidTimestamp);
+        require!(params.emission_rate > 0, StreamError::InvalidEmissionRate);
+        // Assign the next sequential identifier
+        stream.version = StreamAccount::LATEST_VERSION;
+        stream.id = host.total_streams;
+        stream.funder = ctx.accounts.funder.key();
+        stream.mint = ctx.accounts.mint.key();
+        stream.emission_rate = params.emission_rate;
+        stream.time_accrued = time_now as u64;
+        host.total_streams += 1;
+
+        sol_log_compute_units();
+        Ok(())
+    }
+
+    pub fn fund_stream(ctx: Context<FundStreamAccounts>, amount: u64) -> Result<()> {
+        let time_now: i64 = Clock::get()?.unix_timestamp;
+        // Validate time before reassigning to u64
+        require!(time_now > 0, HostError::InvalidTimestamp);
+        require!(amount > 0, TransferError::InvalidAmount);
+        // Settle emission under the previous funding before topping up
+        ctx.accounts.stream.update(time_now as u64)?;
+        token::transfer(ctx.accounts.initialize_fund_context(), amount)?;
+        ctx.accounts.stream.total_funded += amount;
+
+        emit!(StreamFundedEvent {
+            stream: ctx.accounts.stream.key(),
+            sender: ctx.accounts.signer.key(),
+            amount,
+        });
+
+        sol_log_compute_units();
+        Ok(())
+    }
+
+    pub fn claim_stream(
+        ctx: Context<ClaimStreamAccounts>,
+        expected_sequence: Option<u64>,
+    ) -> Result<()> {
+        let time_now: i64 = Clock::get()?.unix_timestamp;
+        let subscription: &mut Account<SubscriptionAccount> = &mut ctx.accounts.subscription;
+        // Validate time before reassigning to u64
+        require!(time_now > 0, HostError::InvalidTimestamp);
+        // Reject stale client state
+        subscription.check_sequence(expected_sequence)?;
+        // Every open bonus campaign, boosting rewards accrued within its window
+        let campaigns = CampaignAccount::load_all(ctx.remaining_accounts, &ctx.accounts.host)?;
+        let total_forfeited: u64 = subscription.total_forfeited;
+        // Settle the matured balance the stream accrues on
+        subscription.mature_slots(time_now as u64, &mut ctx.accounts.host, &campaigns)?;
+        subscription.bump_sequence();
+        // Notify of rewards forfeited while maturing slots
+        if let Some(event) = subscription.forfeited_event(total_forfeited, time_now as u64) {
+            emit!(event);
+        }
+        let locked_amount: u64 = subscription.total_matured;
+        ctx.accounts.stream.update(time_now as u64)?;
+        // Register the member from the current checkpoint on first claim
+        if !ctx.accounts.stream_member.is_registered() {
+            let owner = ctx.accounts.signer.key();
+            ctx.accounts
+                .stream_member
+                .register(&mut ctx.accounts.stream, owner, locked_amount);
+        }
+        let amount = ctx
+            .accounts
+            .stream_member
+            .settle(&mut ctx.accounts.stream, locked_amount)?;
+        // Fail rather than clip, as the checkpoint already moved past this payout
+        require!(
+            amount <= ctx.accounts.vault_token_account.amount,
+            StreamError::InsufficientFunding
+        );
+
+        if amount > 0 {
+            let stream_id = ctx.accounts.stream.id.to_le_bytes();
+            let seeds = [STREAM_SEED_PREFIX, stream_id.as_ref(), &[ctx.bumps.stream]];
+            let signature = &[&seeds[..]];
+            let transfer_context = ctx.accounts.initialize_claim_context();
+            token::transfer(transfer_context.with_signer(signature), amount)?;
+
+            emit!(StreamClaimedEvent {
+                stream: ctx.accounts.stream.key(),
+                sender: ctx.accounts.signer.key(),
+                amount,
+            });
+        }
+
+        sol_log_compute_units();
+        Ok(())
+    }
+
+    pub fn reclaim_stream(ctx: Context<ReclaimStreamAccounts>) -> Result<()> {
+        let stream: &mut Account<StreamAccount> = &mut ctx.accounts.stream;
+        let amount: u64 = stream.total_unallocated;
+        require!(amount > 0, StreamError::NothingToReclaim);
+        require!(
+            amount <= ctx.accounts.vault_token_account.amount,
+            StreamError::InsufficientFunding
+        );
+        stream.total_unallocated = 0;
+
+        let stream_id = stream.id.to_le_bytes();
+        let seeds = [STREAM_SEED_PREFIX, stream_id.as_ref(), &[ctx.bumps.stream]];
+        let signature = &[&seeds[..]];
+        let transfer_context = ctx.accounts.initialize_reclaim_context();
+        token::transfer(transfer_context.with_signer(signature), amount)?;
+
+        emit!(StreamReclaimedEvent {
+            stream: ctx.accounts.stream.key(),
+            funder: ctx.accounts.stream.funder,
+            amount,
+        });
+
+        sol_log_compute_units();
+        Ok(())
+    }
+
+    pub fn create_distribution(
+        ctx: Context<CreateDistributionAccounts>,
+        params: DistributionParams,
+    ) -> Result<()> {
+        let host: &mut Account<HostAccount> = &mut ctx.accounts.host;
+        let id: u64 = host.total_distributions;
+        // Assign the next sequential identifier
+        ctx.accounts.distributor.configure(id, params.clone())?;
+        host.total_distributions += 1;
+
+        emit!(DistributionCreatedEvent {
+            id,
+            root: params.root,
+            total_amount: params.total_amount,
+            total_recipients: params.total_recipients,
+        });
+
+        sol_log_compute_units();
+        Ok(())
+    }
+
+    pub fn claim_distribution(
+        ctx: Context<ClaimDistributionAccounts>,
+        index: u32,
+        amount: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        let signer_key = ctx.accounts.signer.key();
+        // Verify the allocation and mark it claimed
+        ctx.accounts
+            .distributor
+            .claim(index, signer_key, amount, &proof)?;
+
+        if amount > 0 {
+            let seeds = &[REWARDS_SEED_PREFIX, &[ctx.bumps.mint]];
+            let signer = &[&seeds[..]];
+            let mint_context = ctx.accounts.initialize_mint_context();
+            // Mint bonus reward tokens to the member's associated token account
+            token::mint_to(
+                mint_context.with_signer(signer),
+                rewards_to_lamports(amount, ctx.accounts.mint.decimals)?,
+            )?;
+        }
+
+        emit!(DistributionClaimedEvent {
+            distributor: ctx.accounts.distributor.key(),
+            sender: signer_key,
+            index,
+            amount,
+        });
+
+        sol_log_compute_units();
+        Ok(())
+    }
+
     pub fn initialize(ctx: Context<InitializeAccounts>) -> Result<()> {
         let time_now: i64 = Clock::get()?.unix_timestamp;
         // Validate time before reassigning to u64
         require!(time_now > 0, HostError::InvalidTimestamp);
+        ctx.accounts.subscription.version = SubscriptionAccount::LATEST_VERSION;
         // Update timestamps
         ctx.accounts.subscription.time_created = time_now as u64;
         ctx.accounts.subscription.time_rewarded = time_now as u64;
+        // Start accruing from the current global reward checkpoint
+        ctx.accounts.subscription.reward_per_token_paid = ctx.accounts.host.reward_per_token;
+        // Assign the next sequential member number
+        ctx.accounts.host.total_members += 1;
+        ctx.accounts.subscription.member_number = ctx.accounts.host.total_members;
+        // Record the referring member
+        if let Some(referrer) = &mut ctx.accounts.referrer {
+            referrer.add_referral()?;
+            referrer.bump_sequence();
+            ctx.accounts.subscription.referrer = referrer.key();
+        }
         // Output logs
         sol_log_compute_units();
         Ok(())
     }
 
-    pub fn deposit(ctx: Context<TransferAccounts>, amount: u64) -> Result<()> {
+    pub fn migrate_subscription(ctx: Context<MigrateSubscriptionAccounts>) -> Result<()> {
+        let time_now: i64 = Clock::get()?.unix_timestamp;
+        let target = ctx.accounts.subscription.to_account_info();
+        // Validate time before reassigning to u64
+        require!(time_now > 0, HostError::InvalidTimestamp);
+        // Convert the legacy layout before resizing the account
+        let legacy = SubscriptionAccountV1::load(&target.try_borrow_data()?)?;
+        let subscription = legacy.migrate(&mut ctx.accounts.host, time_now as u64)?;
+        subscription.realloc(
+            &target,
+            &ctx.accounts.signer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+        subscription.try_serialize(&mut &mut target.try_borrow_mut_data()?[..])?;
+
+        sol_log_compute_units();
+        Ok(())
+    }
+
+    pub fn loyalty_multiplier(ctx: Context<MemberQueryAccounts>) -> Result<u64> {
+        let time_now: i64 = Clock::get()?.unix_timestamp;
+        // Validate time before reassigning to u64
+        require!(time_now > 0, HostError::InvalidTimestamp);
+
+        Ok(ctx
+            .accounts
+            .subscription
+            .loyalty_multiplier_bps(&ctx.accounts.host, time_now as u64))
+    }
+
+    pub fn preview_rewards(
+        ctx: Context<MemberQueryAccounts>,
+        time_now: Option<i64>,
+    ) -> Result<RewardPreview> {
+        let time_current: i64 = Clock::get()?.unix_timestamp;
+        let time_now: i64 = time_now.unwrap_or(time_current);
+        // Validate time before reassigning to u64
+        require!(time_now >= time_current, HostError::InvalidTimestamp);
+        // Every open bonus campaign, boosting rewards accrued within its window
+        let campaigns = CampaignAccount::load_all(ctx.remaining_accounts, &ctx.accounts.host)?;
+
+        ctx.accounts
+            .subscription
+            .preview(&ctx.accounts.host, &campaigns, time_now as u64)
+    }
+
+    pub fn deposit(
+        ctx: Context<TransferAccounts>,
+        amount: u64,
+        expected_sequence: Option<u64>,
+    ) -> Result<()> {
         let time_now: i64 = Clock::get()?.unix_timestamp;
         let source: &mut Account<TokenAccount> = &mut ctx.accounts.source_token_account;
         // Validate time before reassigning to u64
         require!(time_now > 0, HostError::InvalidTimestamp);
+        // Reject stale client state
+        ctx.accounts
+            .subscription
+            .check_sequence(expected_sequence)?;
         // Validate the amount in valid range
         require!(amount > 0, TransferError::InvalidAmount);
         // Check the source has enough tokens to deposit
@@ -46,10 +402,38 @@ mod reward_program {
             ctx.accounts.subscription.slots.len() < MAX_PENDING_SLOTS,
             LockingError::MaxSlotsExceeded
         );
+        // Every open bonus campaign, boosting rewards accrued within its window
+        let campaigns = CampaignAccount::load_all(ctx.remaining_accounts, &ctx.accounts.host)?;
+        let total_forfeited: u64 = ctx.accounts.subscription.total_forfeited;
         // Execute transfer instruction
         token::transfer(ctx.accounts.initialize_deposit_context(), amount)?;
         // Update subscription account with deposit and reallocate account memory
-        ctx.accounts.subscription.lock(amount, time_now as u64)?;
+        ctx.accounts.subscription.lock(
+            amount,
+            time_now as u64,
+            &mut ctx.accounts.host,
+            &campaigns,
+        )?;
+        // Notify of rewards forfeited while maturing slots
+        let forfeited = ctx
+            .accounts
+            .subscription
+            .forfeited_event(total_forfeited, time_now as u64);
+        if let Some(event) = forfeited {
+            emit!(event);
+        }
+        // The first deposit activates the member
+        let activation = ctx
+            .accounts
+            .subscription
+            .activate(&ctx.accounts.host, time_now as u64);
+        if let Some(reward) = activation {
+            emit!(ActivationEvent {
+                sender: ctx.accounts.signer.key(),
+                reward,
+            });
+        }
+        ctx.accounts.subscription.bump_sequence();
         // Reallocate memory for updated slots
         ctx.accounts.subscription.realloc(
             &ctx.accounts.subscription.to_account_info(),
@@ -61,22 +445,81 @@ mod reward_program {
         Ok(())
     }
 
-    pub fn claim(ctx: Context<ClaimAccounts>) -> Result<()> {
+    pub fn claim(ctx: Context<ClaimAccounts>, expected_sequence: Option<u64>) -> Result<()> {
         let time_now: i64 = Clock::get()?.unix_timestamp;
         let subscription: &mut Account<SubscriptionAccount> = &mut ctx.accounts.subscription;
         // Validate time before reassigning to u64
         require!(time_now > 0, HostError::InvalidTimestamp);
+        // Reject stale client state
+        subscription.check_sequence(expected_sequence)?;
+        // Claims restart the vesting schedule, so only the owner may vest rewards
+        require!(
+            ctx.accounts.signer.key() == ctx.accounts.owner.key()
+                || ctx.accounts.host.vesting_period == 0,
+            MemberError::UnauthorizedClaim
+        );
+        // Referred members must pass their referrer, so its share can't be skipped
+        require!(
+            subscription.referrer == Pubkey::default() || ctx.accounts.referrer.is_some(),
+            MemberError::InvalidReferrer
+        );
+        // Every open bonus campaign, boosting rewards accrued within its window
+        let campaigns = CampaignAccount::load_all(ctx.remaining_accounts, &ctx.accounts.host)?;
+        let total_forfeited: u64 = subscription.total_forfeited;
+
+        let rewards = subscription.claim(time_now as u64, &mut ctx.accounts.host, &campaigns)?;
+        // Notify of rewards forfeited while maturing slots
+        if let Some(event) = subscription.forfeited_event(total_forfeited, time_now as u64) {
+            emit!(event);
+        }
+        // Apply a beneficiary change once its delay has elapsed
+        if subscription.beneficiary.apply(time_now as u64) {
+            emit!(BeneficiaryChangedEvent {
+                owner: ctx.accounts.owner.key(),
+                beneficiary: ctx.accounts.recipient.key(),
+            });
+        }
+        subscription.bump_sequence();
+        // Notify when the epoch budget deferred part of the claim
+        if subscription.total_rewards > 0 {
+            emit!(EmissionCapReachedEvent {
+                sender: ctx.accounts.owner.key(),
+                amount: rewards,
+                deferred: subscription.total_rewards,
+                time_epoch: ctx.accounts.host.time_epoch,
+            });
+        }
 
-        let rewards = subscription.claim(time_now as u64).unwrap();
+        // Credit the referrer a share of the claimed rewards
+        let referrer = ctx.accounts.referrer.as_mut().filter(|_| rewards > 0);
+        if let Some(referrer) = referrer {
+            let amount = referrer.credit_referral(rewards, &ctx.accounts.host, time_now as u64)?;
+            referrer.bump_sequence();
+            // Follow the referrer to its recovered subscription
+            subscription.referrer = referrer.key();
+            emit!(ReferralRewardEvent {
+                referrer: referrer.key(),
+                sender: ctx.accounts.owner.key(),
+                amount,
+            });
+        }
 
-        if rewards > 0 {
+        // Vest claimed rewards instead of minting when configured
+        if rewards > 0 && ctx.accounts.host.vesting_period > 0 {
+            subscription.vest(rewards, ctx.accounts.host.vesting_period, time_now as u64);
+            emit!(RewardsVestingEvent {
+                sender: ctx.accounts.owner.key(),
+                amount: rewards,
+                time_vesting_end: subscription.time_vesting_end,
+            });
+        } else if rewards > 0 {
             let seeds = &[REWARDS_SEED_PREFIX, &[ctx.bumps.mint]];
             let signer = &[&seeds[..]];
             let mint_context = ctx.accounts.initialize_mint_context();
             // Mint reward tokens to the member's associated token account
             token::mint_to(
                 mint_context.with_signer(signer),
-                rewards_to_lamports(rewards),
+                rewards_to_lamports(rewards, ctx.accounts.mint.decimals)?,
             )?;
         }
 
@@ -84,39 +527,272 @@ mod reward_program {
         Ok(())
     }
 
-    pub fn release(ctx: Context<TransferAccounts>, amount: u64) -> Result<()> {
+    pub fn collect_vested(
+        ctx: Context<ClaimAccounts>,
+        expected_sequence: Option<u64>,
+    ) -> Result<()> {
+        let time_now: i64 = Clock::get()?.unix_timestamp;
+        let subscription: &mut Account<SubscriptionAccount> = &mut ctx.accounts.subscription;
+        // Validate time before reassigning to u64
+        require!(time_now > 0, HostError::InvalidTimestamp);
+        // Reject stale client state
+        subscription.check_sequence(expected_sequence)?;
+
+        let amount = subscription.collect_vested(time_now as u64);
+        require!(amount > 0, HostError::NoRewards);
+        subscription.bump_sequence();
+
+        let seeds = &[REWARDS_SEED_PREFIX, &[ctx.bumps.mint]];
+        let signer = &[&seeds[..]];
+        let mint_context = ctx.accounts.initialize_mint_context();
+        // Mint vested reward tokens to the member's associated token account
+        token::mint_to(
+            mint_context.with_signer(signer),
+            rewards_to_lamports(amount, ctx.accounts.mint.decimals)?,
+        )?;
+
+        emit!(VestingCollectedEvent {
+            sender: ctx.accounts.owner.key(),
+            amount,
+        });
+
+        sol_log_compute_units();
+        Ok(())
+    }
+
+    pub fn set_claim_delegate(
+        ctx: Context<ClaimSettingsAccounts>,
+        delegate: Option<Pubkey>,
+        permissionless: bool,
+        expected_sequence: Option<u64>,
+    ) -> Result<()> {
+        let subscription: &mut Account<SubscriptionAccount> = &mut ctx.accounts.subscription;
+        // Reject stale client state
+        subscription.check_sequence(expected_sequence)?;
+        // Clear the delegate when none is given
+        subscription.set_claim_delegate(delegate, permissionless);
+        subscription.bump_sequence();
+
+        sol_log_compute_units();
+        Ok(())
+    }
+
+    pub fn set_beneficiary(
+        ctx: Context<ClaimSettingsAccounts>,
+        beneficiary: Option<Pubkey>,
+        expected_sequence: Option<u64>,
+    ) -> Result<()> {
+        let time_now: i64 = Clock::get()?.unix_timestamp;
+        let owner = ctx.accounts.signer.key();
+        let subscription: &mut Account<SubscriptionAccount> = &mut ctx.accounts.subscription;
+        // Validate time before reassigning to u64
+        require!(time_now > 0, HostError::InvalidTimestamp);
+        // Reject stale client state
+        subscription.check_sequence(expected_sequence)?;
+        // Settle any change that already passed its delay
+        if subscription.beneficiary.apply(time_now as u64) {
+            emit!(BeneficiaryChangedEvent {
+                owner,
+                beneficiary: subscription.reward_recipient(owner, time_now as u64),
+            });
+        }
+        // Schedule the new beneficiary, minting to the owner when none is given
+        let time_effective = subscription
+            .beneficiary
+            .request(beneficiary, BENEFICIARY_DELAY, time_now as u64);
+        subscription.bump_sequence();
+
+        emit!(BeneficiaryRequestedEvent {
+            owner,
+            beneficiary: beneficiary.unwrap_or(owner),
+            time_effective,
+        });
+
+        sol_log_compute_units();
+        Ok(())
+    }
+
+    pub fn redeem_voucher(
+        ctx: Context<RedeemVoucherAccounts>,
+        amount: u64,
+        nonce: u64,
+        time_expiry: u64,
+        expected_sequence: Option<u64>,
+    ) -> Result<()> {
+        let time_now: i64 = Clock::get()?.unix_timestamp;
+        let signer_key = ctx.accounts.signer.key();
+        let voucher_signer = ctx.accounts.host.voucher_signer;
+        // Validate time before reassigning to u64
+        require!(time_now > 0, HostError::InvalidTimestamp);
+        require!(
+            voucher_signer != Pubkey::default(),
+            VoucherError::VouchersDisabled
+        );
+        require!(
+            (time_now as u64) < time_expiry,
+            VoucherError::VoucherExpired
+        );
+        // Reject stale client state
+        ctx.accounts
+            .subscription
+            .check_sequence(expected_sequence)?;
+        // Verify the backend signed this exact voucher for the member
+        let message = VoucherAccount::message(
+            ctx.accounts.host.key(),
+            signer_key,
+            amount,
+            nonce,
+            time_expiry,
+        );
+        VoucherAccount::verify_signature(&ctx.accounts.instructions, voucher_signer, &message)?;
+        // Record the nonce so the voucher can't be replayed
+        ctx.accounts.voucher.set_inner(VoucherAccount {
+            version: VoucherAccount::LATEST_VERSION,
+            nonce,
+            member: signer_key,
+            time_redeemed: time_now as u64,
+        });
+        ctx.accounts
+            .subscription
+            .credit_rewards(amount, time_now as u64);
+        ctx.accounts.subscription.bump_sequence();
+
+        emit!(VoucherRedeemedEvent {
+            sender: signer_key,
+            amount,
+            nonce,
+        });
+
+        sol_log_compute_units();
+        Ok(())
+    }
+
+    pub fn release(
+        ctx: Context<TransferAccounts>,
+        amount: u64,
+        expected_sequence: Option<u64>,
+    ) -> Result<()> {
         let time_now: i64 = Clock::get()?.unix_timestamp;
         let vault: &mut Account<TokenAccount> = &mut ctx.accounts.vault_token_account;
         let subscription: &mut Account<SubscriptionAccount> = &mut ctx.accounts.subscription;
         // Validate time before reassigning to u64
         require!(time_now > 0, HostError::InvalidTimestamp);
+        // Reject stale client state
+        subscription.check_sequence(expected_sequence)?;
         // Check vault token account has sufficient balance
         require!(amount <= vault.amount, TransferError::InvalidBalance);
+        // Every open bonus campaign, boosting rewards accrued within its window
+        let campaigns = CampaignAccount::load_all(ctx.remaining_accounts, &ctx.accounts.host)?;
+        let total_forfeited: u64 = subscription.total_forfeited;
         // Update pool attributes
-        subscription.unlock(amount, time_now as u64)?;
+        let withdrawal =
+            subscription.unlock(amount, time_now as u64, &mut ctx.accounts.host, &campaigns)?;
+        subscription.bump_sequence();
+        // Notify of rewards forfeited while maturing slots
+        if let Some(event) = subscription.forfeited_event(total_forfeited, time_now as u64) {
+            emit!(event);
+        }
+        // Notify the member of the extended unbonding period
+        if let Transaction::Withdraw {
+            time_released,
+            delayed: true,
+            ..
+        } = withdrawal
+        {
+            emit!(ReleaseDelayedEvent {
+                sender: ctx.accounts.signer.key(),
+                amount,
+                time_released,
+            });
+        }
+        // Resize allocated space for member account
+        ctx.accounts.subscription.realloc(
+            &ctx.accounts.subscription.to_account_info(),
+            &ctx.accounts.signer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+
+        sol_log_compute_units();
+        Ok(())
+    }
+
+    pub fn cancel_release(
+        ctx: Context<CancelReleaseAccounts>,
+        index: u8,
+        expected_sequence: Option<u64>,
+    ) -> Result<()> {
+        let time_now: i64 = Clock::get()?.unix_timestamp;
+        let subscription: &mut Account<SubscriptionAccount> = &mut ctx.accounts.subscription;
+        // Validate time before reassigning to u64
+        require!(time_now > 0, HostError::InvalidTimestamp);
+        // Reject stale client state
+        subscription.check_sequence(expected_sequence)?;
+        // Every open bonus campaign, boosting rewards accrued within its window
+        let campaigns = CampaignAccount::load_all(ctx.remaining_accounts, &ctx.accounts.host)?;
+        let total_forfeited: u64 = subscription.total_forfeited;
+        // Return the pending release to the locked balance
+        let amount = subscription.cancel_release(
+            index as usize,
+            time_now as u64,
+            &mut ctx.accounts.host,
+            &campaigns,
+        )?;
+        subscription.bump_sequence();
+        // Notify of rewards forfeited while accruing
+        if let Some(event) = subscription.forfeited_event(total_forfeited, time_now as u64) {
+            emit!(event);
+        }
+
+        emit!(ReleaseCancelledEvent {
+            sender: ctx.accounts.signer.key(),
+            amount,
+        });
         // Resize allocated space for member account
         ctx.accounts.subscription.realloc(
             &ctx.accounts.subscription.to_account_info(),
             &ctx.accounts.signer.to_account_info(),
             &ctx.accounts.system_program.to_account_info(),
         )?;
-        
-        
+
         sol_log_compute_units();
         Ok(())
     }
 
-    pub fn withdraw(ctx: Context<TransferAccounts>) -> Result<()> {
+    pub fn withdraw(ctx: Context<WithdrawAccounts>, expected_sequence: Option<u64>) -> Result<()> {
         let time_now: i64 = Clock::get()?.unix_timestamp;
+        let signer_key = ctx.accounts.signer.key();
         let subscription: &mut Account<SubscriptionAccount> = &mut ctx.accounts.subscription;
         // Validate time before reassigning to u64
         require!(time_now > 0, HostError::InvalidTimestamp);
+        // Reject stale client state
+        subscription.check_sequence(expected_sequence)?;
+        // Apply a withdrawal address change once its delay has elapsed
+        if subscription.withdrawal_address.apply(time_now as u64) {
+            emit!(WithdrawalAddressChangedEvent {
+                owner: signer_key,
+                destination: subscription.withdrawal_destination(signer_key, time_now as u64),
+            });
+        }
+        // Check the destination belongs to the registered withdrawal address
+        require!(
+            ctx.accounts.destination_token_account.owner
+                == subscription.withdrawal_destination(signer_key, time_now as u64),
+            TransferError::InvalidDestination
+        );
+        // Every open bonus campaign, boosting rewards accrued within its window
+        let campaigns = CampaignAccount::load_all(ctx.remaining_accounts, &ctx.accounts.host)?;
+        let total_forfeited: u64 = subscription.total_forfeited;
         // Request the transfer amount
-        let amount = subscription.on_withdraw(time_now as u64)?;
+        let amount =
+            subscription.on_withdraw(time_now as u64, &mut ctx.accounts.host, &campaigns)?;
+        subscription.bump_sequence();
+        // Notify of rewards forfeited while maturing slots
+        if let Some(event) = subscription.forfeited_event(total_forfeited, time_now as u64) {
+            emit!(event);
+        }
         // Validate the transfer amount
         if amount > 0 {
             let bump = ctx.bumps.subscription;
-            let signer_key = ctx.accounts.signer.key();
             // Derive program signature
             let seeds = [
                 SUBSCRIPTION_SEED_PREFIX.as_ref(),
@@ -134,4 +810,171 @@ mod reward_program {
         sol_log_compute_units();
         Ok(())
     }
+
+    pub fn set_withdrawal_address(
+        ctx: Context<WithdrawalAddressAccounts>,
+        destination: Option<Pubkey>,
+        expected_sequence: Option<u64>,
+    ) -> Result<()> {
+        let time_now: i64 = Clock::get()?.unix_timestamp;
+        let subscription: &mut Account<SubscriptionAccount> = &mut ctx.accounts.subscription;
+        // Validate time before reassigning to u64
+        require!(time_now > 0, HostError::InvalidTimestamp);
+        // Reject stale client state
+        subscription.check_sequence(expected_sequence)?;
+        // Settle any change that already passed its delay
+        if subscription.withdrawal_address.apply(time_now as u64) {
+            emit!(WithdrawalAddressChangedEvent {
+                owner: ctx.accounts.signer.key(),
+                destination: subscription
+                    .withdrawal_destination(ctx.accounts.signer.key(), time_now as u64),
+            });
+        }
+        // Schedule the new destination, clearing it when none is given
+        let time_effective = subscription
+            .withdrawal_address
+            .request(destination, WITHDRAWAL_ADDRESS_DELAY, time_now as u64);
+        subscription.bump_sequence();
+
+        emit!(WithdrawalAddressRequestedEvent {
+            owner: ctx.accounts.signer.key(),
+            destination: destination.unwrap_or(ctx.accounts.signer.key()),
+            time_effective,
+        });
+
+        sol_log_compute_units();
+        Ok(())
+    }
+
+    pub fn set_guardians(
+        ctx: Context<GuardianAccounts>,
+        guardians: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        let recovery: &mut Account<RecoveryAccount> = &mut ctx.accounts.recovery;
+        // Bind the recovery account to the owner on first registration
+        recovery.version = RecoveryAccount::LATEST_VERSION;
+        recovery.owner = ctx.accounts.signer.key();
+        recovery.set_guardians(guardians, threshold)?;
+
+        sol_log_compute_units();
+        Ok(())
+    }
+
+    pub fn propose_recovery(
+        ctx: Context<ProposeRecoveryAccounts>,
+        new_owner: Pubkey,
+    ) -> Result<()> {
+        let time_now: i64 = Clock::get()?.unix_timestamp;
+        let recovery: &mut Account<RecoveryAccount> = &mut ctx.accounts.recovery;
+        let guardian = ctx.accounts.guardian.key();
+        // Validate time before reassigning to u64
+        require!(time_now > 0, HostError::InvalidTimestamp);
+        // Open the veto window for the owner
+        recovery.propose(guardian, new_owner, time_now as u64)?;
+
+        emit!(RecoveryProposedEvent {
+            owner: recovery.owner,
+            new_owner,
+            guardian,
+        });
+
+        sol_log_compute_units();
+        Ok(())
+    }
+
+    pub fn approve_recovery(
+        ctx: Context<ProposeRecoveryAccounts>,
+        new_owner: Pubkey,
+    ) -> Result<()> {
+        let recovery: &mut Account<RecoveryAccount> = &mut ctx.accounts.recovery;
+        let guardian = ctx.accounts.guardian.key();
+
+        recovery.approve(guardian, new_owner)?;
+
+        emit!(RecoveryApprovedEvent {
+            owner: recovery.owner,
+            new_owner,
+            guardian,
+        });
+
+        sol_log_compute_units();
+        Ok(())
+    }
+
+    pub fn veto_recovery(ctx: Context<VetoRecoveryAccounts>) -> Result<()> {
+        let recovery: &mut Account<RecoveryAccount> = &mut ctx.accounts.recovery;
+        let new_owner = recovery.new_owner;
+
+        recovery.veto()?;
+
+        emit!(RecoveryVetoedEvent {
+            owner: recovery.owner,
+            new_owner,
+        });
+
+        sol_log_compute_units();
+        Ok(())
+    }
+
+    pub fn execute_recovery(
+        ctx: Context<ExecuteRecoveryAccounts>,
+        expected_sequence: Option<u64>,
+    ) -> Result<()> {
+        let time_now: i64 = Clock::get()?.unix_timestamp;
+        let owner = ctx.accounts.recovery.owner;
+        let new_owner = ctx.accounts.new_owner.key();
+        // Validate time before reassigning to u64
+        require!(time_now > 0, HostError::InvalidTimestamp);
+        // Reject stale client state
+        ctx.accounts
+            .subscription
+            .check_sequence(expected_sequence)?;
+        // Check approvals and the veto window
+        ctx.accounts.recovery.execute(time_now as u64)?;
+        // Rebind the member state to the new owner
+        let mut subscription: SubscriptionAccount = (*ctx.accounts.subscription).clone();
+        subscription.recover_from(owner);
+        subscription.bump_sequence();
+        ctx.accounts.new_subscription.set_inner(subscription);
+        // Derive program signature of the previous subscription
+        let bump = ctx.bumps.subscription;
+        let seeds = [SUBSCRIPTION_SEED_PREFIX, owner.as_ref(), &[bump]];
+        let signature = &[&seeds[..]];
+        // Migrate locked tokens into the new owner's vault
+        let amount = ctx.accounts.vault_token_account.amount;
+        if amount > 0 {
+            let transfer_context = ctx.accounts.initialize_migrate_context();
+            token::transfer(transfer_context.with_signer(signature), amount)?;
+        }
+        // Close the emptied vault bound to the lost key
+        let close_context = ctx.accounts.initialize_close_context();
+        token::close_account(close_context.with_signer(signature))?;
+
+        emit!(RecoveryExecutedEvent {
+            owner,
+            new_owner,
+            amount,
+        });
+
+        sol_log_compute_units();
+        Ok(())
+    }
+
+    pub fn migrate_stream_member(ctx: Context<MigrateStreamMemberAccounts>) -> Result<()> {
+        let new_owner = ctx.accounts.signer.key();
+        // Rebind the stream checkpoint of the previous owner
+        let mut stream_member: StreamMemberAccount = (*ctx.accounts.stream_member).clone();
+        stream_member.owner = new_owner;
+        ctx.accounts.new_stream_member.set_inner(stream_member);
+
+        emit!(StreamMemberMigratedEvent {
+            stream: ctx.accounts.stream.key(),
+            owner: ctx.accounts.subscription.recovered_from,
+            new_owner,
+        });
+
+        sol_log_compute_units();
+        Ok(())
+    }
 }
@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use crate::{constants::REWARD_FACTOR, errors::MathError};
+
+/** Fixed-point scale of fractional reward units */
+pub const REWARD_PRECISION: u128 = 1_000_000_000;
+
+/** Denominator of multipliers expressed in basis points */
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+/** Upper bound of configured reward multipliers, ten times the base rate */
+pub const MAX_MULTIPLIER_BPS: u64 = 100_000;
+
+/** Fixed-point scale of the reward per token accumulator */
+pub const ACCUMULATOR_PRECISION: u128 = 1_000_000_000_000_000_000;
+
+/** Fixed-point rewards granted for `lamports` locked over a full reward period */
+pub fn lamports_to_rewards(lamports: u64, decimals: u8) -> Result<u128> {
+    accrued_rewards(lamports, 1, 1, decimals)
+}
+
+/** Fixed-point rewards accrued by `lamports` over `time_elapsed` of a reward `period` */
+pub fn accrued_rewards(
+    lamports: u64,
+    time_elapsed: u64,
+    period: u64,
+    decimals: u8,
+) -> Result<u128> {
+    let numerator: u128 = (lamports as u128)
+        .checked_mul(time_elapsed as u128)
+        .and_then(|value| value.checked_mul(REWARD_PRECISION))
+        .ok_or(MathError::Overflow)?;
+    let denominator: u128 = (period as u128)
+        .checked_mul(10u128.pow(decimals as u32))
+        .and_then(|value| value.checked_mul(REWARD_FACTOR as u128))
+        .ok_or(MathError::Overflow)?;
+    numerator
+        .checked_div(denominator)
+        .ok_or(error!(MathError::DivisionByZero))
+}
+
+/** Scales fixed-point `rewards` by a multiplier in basis points */
+pub fn apply_bps(rewards: u128, multiplier_bps: u64) -> Result<u128> {
+    rewards
+        .checked_mul(multiplier_bps as u128)
+        .map(|value| value / BPS_DENOMINATOR as u128)
+        .ok_or(error!(MathError::Overflow))
+}
+
+/** Splits fixed-point `rewards` into whole units, carrying the fraction in `remainder` */
+pub fn carry_rewards(rewards: u128, remainder: &mut u64) -> Result<u64> {
+    let total: u128 = rewards
+        .checked_add(*remainder as u128)
+        .ok_or(MathError::Overflow)?;
+    *remainder = (total % REWARD_PRECISION) as u64;
+    u64::try_from(total / REWARD_PRECISION).map_err(|_| error!(MathError::Overflow))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn carries_fractional_rewards() {
+        let mut remainder: u64 = 0;
+        // Three thirds of a unit add up to one whole unit
+        let third: u128 = REWARD_PRECISION / 3 + 1;
+        assert_eq!(carry_rewards(third, &mut remainder).unwrap(), 0);
+        assert_eq!(carry_rewards(third, &mut remainder).unwrap(), 0);
+        assert_eq!(carry_rewards(third, &mut remainder).unwrap(), 1);
+        assert_eq!(remainder, 2);
+        assert!(carry_rewards(u128::MAX, &mut remainder).is_err());
+    }
+
+    #[test]
+    fn accrues_per_second() {
+        let lamports: u64 = REWARD_FACTOR * 1_000_000_000;
+        assert_eq!(lamports_to_rewards(lamports, 9).unwrap(), REWARD_PRECISION);
+        // Half the period accrues half a unit, which is carried rather than truncated
+        assert_eq!(
+            accrued_rewards(lamports, 50, 100, 9).unwrap(),
+            REWARD_PRECISION / 2
+        );
+        assert!(accrued_rewards(lamports, 1, 0, 9).is_err());
+    }
+
+    #[test]
+    fn scales_by_mint_decimals() {
+        // The same whole token amount earns equally regardless of the mint decimals
+        let tokens: u64 = REWARD_FACTOR * 3;
+        assert_eq!(
+            lamports_to_rewards(tokens * 1_000_000, 6).unwrap(),
+            lamports_to_rewards(tokens * 1_000_000_000, 9).unwrap()
+        );
+        assert_eq!(
+            lamports_to_rewards(tokens, 0).unwrap(),
+            3 * REWARD_PRECISION
+        );
+    }
+
+    #[test]
+    fn scales_by_basis_points() {
+        assert_eq!(
+            apply_bps(REWARD_PRECISION, 15_000).unwrap(),
+            3 * REWARD_PRECISION / 2
+        );
+        assert_eq!(apply_bps(REWARD_PRECISION, 0).unwrap(), 0);
+        assert!(apply_bps(u128::MAX, 2).is_err());
+    }
+}
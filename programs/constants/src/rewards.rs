@@ -0,0 +1,240 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::MATURATION_PERIOD,
+    errors::{HostError, MathError},
+    math::{accrued_rewards, ACCUMULATOR_PRECISION, BPS_DENOMINATOR},
+    state::{
+        campaign::{boosted_multiplier_bps, boosted_time, CampaignAccount},
+        host::HostAccount,
+        subscription::SubscriptionAccount,
+    },
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, PartialEq, Debug)]
+pub enum RewardModelKind {
+    FixedEpoch, // Fixed entries per token locked each epoch
+    ProRata,    // Fixed emission shared across all locked tokens
+}
+
+impl TryFrom<u8> for RewardModelKind {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self> {
+        match value {
+            0 => Ok(RewardModelKind::FixedEpoch),
+            1 => Ok(RewardModelKind::ProRata),
+            _ => err!(HostError::InvalidRewardModel),
+        }
+    }
+}
+
+impl RewardModelKind {
+    pub fn to_u8(&self) -> u8 {
+        match self {
+            RewardModelKind::FixedEpoch => 0,
+            RewardModelKind::ProRata => 1,
+        }
+    }
+
+    pub fn model(&self) -> &'static dyn RewardModel {
+        match self {
+            RewardModelKind::FixedEpoch => &FixedEpochModel,
+            RewardModelKind::ProRata => &ProRataModel,
+        }
+    }
+}
+
+/**
+ Determines the entries accrued by members. All amounts are
+ fixed-point rewards scaled by `REWARD_PRECISION`, which the
+ subscription carries into whole entry tokens. Campaigns are
+ the open bonus campaigns passed to the instruction.
+ */
+pub trait RewardModel {
+    /** Advances any global reward state on the host up to `time_now` */
+    fn update(&self, host: &mut HostAccount, time_now: u64) -> Result<()>;
+
+    /** Rewards granted immediately when `amount` is deposited */
+    fn deposit_rewards(
+        &self,
+        host: &HostAccount,
+        campaigns: &[CampaignAccount],
+        amount: u64,
+        time_now: u64,
+    ) -> Result<u128>;
+
+    /** Rewards granted when a deposit slot of `amount` completes its maturation epoch */
+    fn matured_rewards(
+        &self,
+        host: &HostAccount,
+        campaigns: &[CampaignAccount],
+        amount: u64,
+        time_created: u64,
+        time_matured: u64,
+        time_now: u64,
+    ) -> Result<u128>;
+
+    /** Rewards accrued by the matured balance since the member was last rewarded */
+    fn accrued_rewards(
+        &self,
+        host: &HostAccount,
+        campaigns: &[CampaignAccount],
+        subscription: &SubscriptionAccount,
+        time_now: u64,
+    ) -> Result<u128>;
+}
+
+/**
+ Grants a fixed ratio of entries per token locked for each
+ epoch, independent of other members. Deposits earn entries
+ immediately and for every second of their maturation epoch.
+ */
+pub struct FixedEpochModel;
+
+impl RewardModel for FixedEpochModel {
+    fn update(&self, _host: &mut HostAccount, _time_now: u64) -> Result<()> {
+        Ok(())
+    }
+
+    fn deposit_rewards(
+        &self,
+        host: &HostAccount,
+        campaigns: &[CampaignAccount],
+        amount: u64,
+        time_now: u64,
+    ) -> Result<u128> {
+        // One epoch of entries at the scheduled multiplier
+        accrued_rewards(
+            amount,
+            boosted_multiplier_bps(&host.schedule, campaigns, time_now)?,
+            BPS_DENOMINATOR,
+            host.mint_decimals,
+        )
+    }
+
+    fn matured_rewards(
+        &self,
+        host: &HostAccount,
+        campaigns: &[CampaignAccount],
+        amount: u64,
+        time_created: u64,
+        time_matured: u64,
+        time_now: u64,
+    ) -> Result<u128> {
+        // Accrue every second since the deposit, including the maturation epoch
+        let epoch_length: u64 = time_matured - time_created;
+        let time_weighted: u64 = boosted_time(&host.schedule, campaigns, time_created, time_now)?;
+        accrued_rewards(
+            amount,
+            time_weighted,
+            epoch_length * BPS_DENOMINATOR,
+            host.mint_decimals,
+        )
+    }
+
+    fn accrued_rewards(
+        &self,
+        host: &HostAccount,
+        campaigns: &[CampaignAccount],
+        subscription: &SubscriptionAccount,
+        time_now: u64,
+    ) -> Result<u128> {
+        let time_weighted: u64 = boosted_time(
+            &host.schedule,
+            campaigns,
+            subscription.time_rewarded,
+            time_now,
+        )?;
+        accrued_rewards(
+            subscription.total_matured,
+            time_weighted,
+            MATURATION_PERIOD * BPS_DENOMINATOR,
+            host.mint_decimals,
+        )
+    }
+}
+
+/**
+ Emits a fixed `emission_rate` of entries per second shared
+ pro-rata across the host `total_locked` matured balance.
+ Deposits only start earning once matured, and only join
+ `total_locked` when the next instruction of their owner
+ matures them, so the emission is split across the settled
+ balance until then. Tier, loyalty and early adopter
+ multipliers scale each share on top, so total emission
+ exceeds `emission_rate` when configured and is bounded by
+ the epoch emission cap instead. Campaigns don't apply since
+ the shared accumulator isn't tracked per member.
+ */
+pub struct ProRataModel;
+
+impl RewardModel for ProRataModel {
+    fn update(&self, host: &mut HostAccount, time_now: u64) -> Result<()> {
+        let time_weighted: u64 = host.schedule.weighted_time(host.time_accrued, time_now)?;
+        // Emission while nothing is locked is not distributed
+        if host.total_locked > 0 && time_weighted > 0 {
+            let increment: u128 = (host.emission_rate as u128)
+                .checked_mul(time_weighted as u128)
+                .and_then(|value| value.checked_mul(ACCUMULATOR_PRECISION))
+                .ok_or(MathError::Overflow)?
+                / (host.total_locked as u128 * BPS_DENOMINATOR as u128);
+            host.reward_per_token = host
+                .reward_per_token
+                .checked_add(increment)
+                .ok_or(MathError::Overflow)?;
+        }
+        host.time_accrued = host.time_accrued.max(time_now);
+        Ok(())
+    }
+
+    fn deposit_rewards(
+        &self,
+        _host: &HostAccount,
+        _campaigns: &[CampaignAccount],
+        _amount: u64,
+        _time_now: u64,
+    ) -> Result<u128> {
+        Ok(0)
+    }
+
+    fn matured_rewards(
+        &self,
+        _host: &HostAccount,
+        _campaigns: &[CampaignAccount],
+        _amount: u64,
+        _time_created: u64,
+        _time_matured: u64,
+        _time_now: u64,
+    ) -> Result<u128> {
+        Ok(0)
+    }
+
+    fn accrued_rewards(
+        &self,
+        host: &HostAccount,
+        _campaigns: &[CampaignAccount],
+        subscription: &SubscriptionAccount,
+        _time_now: u64,
+    ) -> Result<u128> {
+        let delta: u128 = host
+            .reward_per_token
+            .saturating_sub(subscription.reward_per_token_paid);
+        let rewards: u128 = (subscription.total_matured as u128)
+            .checked_mul(delta)
+            .ok_or(MathError::Overflow)?;
+        Ok(rewards / ACCUMULATOR_PRECISION)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_reward_model_kind() {
+        for kind in [RewardModelKind::FixedEpoch, RewardModelKind::ProRata] {
+            assert_eq!(RewardModelKind::try_from(kind.to_u8()).unwrap(), kind);
+        }
+        assert!(RewardModelKind::try_from(2).is_err());
+    }
+}
@@ -0,0 +1,208 @@
+use anchor_lang::prelude::*;
+use crate::constants::{MATURATION_PERIOD, MAX_REWARD_BUCKETS};
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, Debug, Default)]
+pub struct RewardBucket {
+    /** Epoch the rewards accrued in, counted in maturation periods */
+    pub epoch: u64,
+    /** Unclaimed entry tokens accrued during the epoch, zero marks an unused bucket */
+    pub amount: u64,
+}
+
+/**
+ Unclaimed rewards grouped by the epoch they accrued in, so
+ only rewards left unclaimed past the expiry are forfeited.
+ Buckets are ordered oldest first with unused buckets
+ trailing. Once full, the oldest bucket merges into the next
+ and expires with the later epoch, which the host bounds by
+ keeping the expiry below `MAX_REWARD_BUCKETS` epochs.
+ */
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, Debug, Default)]
+pub struct RewardBuckets {
+    pub buckets: [RewardBucket; MAX_REWARD_BUCKETS],
+}
+
+impl RewardBuckets {
+    pub fn epoch(time_now: u64) -> u64 {
+        time_now / MATURATION_PERIOD
+    }
+
+    /** Records `amount` accrued at `time_now` */
+    pub fn credit(&mut self, amount: u64, time_now: u64) {
+        if amount == 0 {
+            return;
+        }
+        let epoch: u64 = Self::epoch(time_now);
+        let mut count: usize = self.active_count();
+        let index: usize = self.buckets[..count]
+            .iter()
+            .take_while(|bucket| bucket.epoch < epoch)
+            .count();
+        if index < count && self.buckets[index].epoch == epoch {
+            self.buckets[index].amount += amount;
+            return;
+        }
+        if count == MAX_REWARD_BUCKETS {
+            // Merge the oldest bucket into the next to free a bucket
+            if index == 0 {
+                self.buckets[0].amount += amount;
+                return;
+            }
+            self.buckets[1].amount += self.buckets[0].amount;
+            self.buckets.rotate_left(1);
+            count -= 1;
+            return self.insert(index - 1, count, RewardBucket { epoch, amount });
+        }
+        self.insert(index, count, RewardBucket { epoch, amount });
+    }
+
+    /**
+     Records `amount` accrued evenly over `time_from` to
+     `time_to` in the epochs it accrued in. The share accrued
+     in epochs already past `expiry_epochs` is not recorded and
+     returned as forfeited.
+     */
+    pub fn credit_range(
+        &mut self,
+        amount: u64,
+        time_from: u64,
+        time_to: u64,
+        expiry_epochs: u64,
+    ) -> u64 {
+        if expiry_epochs == 0 || time_to <= time_from {
+            self.credit(amount, time_to);
+            return 0;
+        }
+        let time_elapsed: u128 = (time_to - time_from) as u128;
+        let share = |time_start: u64, time_end: u64| -> u64 {
+            (amount as u128 * (time_end - time_start) as u128 / time_elapsed) as u64
+        };
+        // Epochs before the oldest one still within the expiry are forfeited
+        let epoch_live: u64 = (Self::epoch(time_to) + 1).saturating_sub(expiry_epochs);
+        let mut time_segment: u64 = time_from.max(epoch_live * MATURATION_PERIOD).min(time_to);
+        let forfeited: u64 = share(time_from, time_segment);
+        let mut remaining: u64 = amount - forfeited;
+        while time_segment < time_to {
+            let time_end: u64 = ((Self::epoch(time_segment) + 1) * MATURATION_PERIOD).min(time_to);
+            // The last epoch takes the rounding remainder
+            let credited: u64 = if time_end == time_to {
+                remaining
+            } else {
+                share(time_segment, time_end)
+            };
+            self.credit(credited, time_segment);
+            remaining -= credited;
+            time_segment = time_end;
+        }
+        forfeited
+    }
+
+    /** Consumes `amount` of claimed rewards from the oldest buckets first */
+    pub fn debit(&mut self, amount: u64) {
+        let mut remaining: u64 = amount;
+        for bucket in self.buckets.iter_mut() {
+            let debited: u64 = remaining.min(bucket.amount);
+            bucket.amount -= debited;
+            remaining -= debited;
+        }
+        self.compact();
+    }
+
+    /** Removes buckets older than `expiry_epochs`, returning the amount forfeited */
+    pub fn expire(&mut self, expiry_epochs: u64, time_now: u64) -> u64 {
+        let epoch: u64 = Self::epoch(time_now);
+        let mut forfeited: u64 = 0;
+        for bucket in self.buckets.iter_mut() {
+            if bucket.amount > 0 && bucket.epoch + expiry_epochs <= epoch {
+                forfeited += bucket.amount;
+                bucket.amount = 0;
+            }
+        }
+        self.compact();
+        forfeited
+    }
+
+    fn insert(&mut self, index: usize, count: usize, bucket: RewardBucket) {
+        self.buckets[index..=count].rotate_right(1);
+        self.buckets[index] = bucket;
+    }
+
+    fn compact(&mut self) {
+        let mut count: usize = 0;
+        for index in 0..MAX_REWARD_BUCKETS {
+            if self.buckets[index].amount > 0 {
+                self.buckets[count] = self.buckets[index];
+                count += 1;
+            }
+        }
+        for bucket in self.buckets[count..].iter_mut() {
+            *bucket = RewardBucket::default();
+        }
+    }
+
+    fn active_count(&self) -> usize {
+        self.buckets
+            .iter()
+            .take_while(|bucket| bucket.amount > 0)
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn amounts(buckets: &RewardBuckets) -> Vec<(u64, u64)> {
+        buckets.buckets[..buckets.active_count()]
+            .iter()
+            .map(|bucket| (bucket.epoch, bucket.amount))
+            .collect()
+    }
+
+    #[test]
+    fn credits_in_epoch_order() {
+        let mut buckets = RewardBuckets::default();
+        buckets.credit(10, 2 * MATURATION_PERIOD);
+        buckets.credit(5, 0);
+        buckets.credit(7, 2 * MATURATION_PERIOD + 1);
+        assert_eq!(amounts(&buckets), vec![(0, 5), (2, 17)]);
+    }
+
+    #[test]
+    fn merges_oldest_bucket_when_full() {
+        let mut buckets = RewardBuckets::default();
+        for epoch in 0..MAX_REWARD_BUCKETS as u64 {
+            buckets.credit(1, epoch * MATURATION_PERIOD);
+        }
+        buckets.credit(1, 20 * MATURATION_PERIOD);
+        let credited = amounts(&buckets);
+        assert_eq!(credited.len(), MAX_REWARD_BUCKETS);
+        assert_eq!(credited[0], (1, 2));
+        assert_eq!(credited[MAX_REWARD_BUCKETS - 1], (20, 1));
+    }
+
+    #[test]
+    fn debits_oldest_first_and_expires() {
+        let mut buckets = RewardBuckets::default();
+        buckets.credit(10, 0);
+        buckets.credit(10, MATURATION_PERIOD);
+        buckets.credit(10, 2 * MATURATION_PERIOD);
+        buckets.debit(15);
+        assert_eq!(amounts(&buckets), vec![(1, 5), (2, 10)]);
+        assert_eq!(buckets.expire(2, 3 * MATURATION_PERIOD), 5);
+        assert_eq!(amounts(&buckets), vec![(2, 10)]);
+    }
+
+    #[test]
+    fn splits_range_across_epochs() {
+        let mut buckets = RewardBuckets::default();
+        // Evenly accrued over four epochs, the oldest two already expired
+        let forfeited: u64 = buckets.credit_range(400, 0, 4 * MATURATION_PERIOD - 1, 2);
+        assert_eq!(forfeited, 200);
+        assert_eq!(amounts(&buckets), vec![(2, 100), (3, 100)]);
+        // Without expiry everything is recorded at the end of the range
+        let mut buckets = RewardBuckets::default();
+        assert_eq!(buckets.credit_range(400, 0, 4 * MATURATION_PERIOD, 0), 0);
+        assert_eq!(amounts(&buckets), vec![(4, 400)]);
+    }
+}
@@ -0,0 +1,192 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    errors::{HostError, MathError},
+    math::{BPS_DENOMINATOR, MAX_MULTIPLIER_BPS},
+    state::{host::HostAccount, schedule::EmissionSchedule},
+};
+
+/**
+ Admin created promotion boosting the entries accrued between
+ `time_start` and `time_end`. Instructions settling rewards
+ take every open campaign as remaining accounts, so callers
+ can't skip a boost. Campaigns can be closed once members
+ had a maturation period after the end to settle the boost.
+ */
+#[account]
+pub struct CampaignAccount {
+    /** Schema version from v0 up to v255. Defaults to the `LATEST_VERSION` constant. */
+    pub version: u8,
+    /** Sequential identifier assigned from the host `total_campaigns` counter */
+    pub id: u64,
+    /** Date the campaign starts boosting rewards */
+    pub time_start: u64,
+    /** Date the campaign stops boosting rewards */
+    pub time_end: u64,
+    /** Reward multiplier in basis points applied within the window */
+    pub multiplier_bps: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone)]
+pub struct CampaignParams {
+    pub time_start: u64,
+    pub time_end: u64,
+    pub multiplier_bps: u64,
+}
+
+impl CampaignAccount {
+    pub const LATEST_VERSION: u8 = 1;
+
+    pub const fn space() -> usize {
+        ANCHOR_DISCRIMINATOR_SIZE + std::mem::size_of::<CampaignAccount>()
+    }
+
+    pub fn configure(&mut self, id: u64, params: CampaignParams, time_now: u64) -> Result<()> {
+        // Campaigns only boost rewards and can't start in the past
+        require!(
+            params.time_start >= time_now
+                && params.time_end > params.time_start
+                && params.multiplier_bps > BPS_DENOMINATOR,
+            HostError::InvalidCampaign
+        );
+        require!(
+            params.multiplier_bps <= MAX_MULTIPLIER_BPS,
+            HostError::InvalidMultiplier
+        );
+        self.version = Self::LATEST_VERSION;
+        self.id = id;
+        self.time_start = params.time_start;
+        self.time_end = params.time_end;
+        self.multiplier_bps = params.multiplier_bps;
+        Ok(())
+    }
+
+    pub fn is_active(&self, time_now: u64) -> bool {
+        time_now >= self.time_start && time_now < self.time_end
+    }
+
+    /** Checks if the settlement period following the campaign window has elapsed */
+    pub fn is_closable(&self, time_now: u64) -> bool {
+        time_now >= self.time_end.saturating_add(MATURATION_PERIOD)
+    }
+
+    /** Weighted time of `schedule` added by the campaign over `time_from` to `time_to` */
+    pub fn bonus_time(
+        &self,
+        schedule: &EmissionSchedule,
+        time_from: u64,
+        time_to: u64,
+    ) -> Result<u64> {
+        let time_weighted: u64 =
+            schedule.weighted_time(time_from.max(self.time_start), time_to.min(self.time_end))?;
+        self.bonus(time_weighted)
+    }
+
+    /** Share of `value` added by the campaign multiplier */
+    fn bonus(&self, value: u64) -> Result<u64> {
+        let bonus: u128 = (value as u128)
+            .checked_mul((self.multiplier_bps - BPS_DENOMINATOR) as u128)
+            .ok_or(MathError::Overflow)?
+            / BPS_DENOMINATOR as u128;
+        u64::try_from(bonus).map_err(|_| error!(MathError::Overflow))
+    }
+
+    /** Deserializes the open campaigns passed as remaining accounts, rejecting duplicates */
+    pub fn load_all(accounts: &[AccountInfo], host: &HostAccount) -> Result<Vec<CampaignAccount>> {
+        // Campaign accounts only exist while open, so the count proves the set is complete
+        require!(
+            accounts.len() as u64 == host.open_campaigns,
+            HostError::MissingCampaigns
+        );
+        let mut campaigns: Vec<CampaignAccount> = Vec::with_capacity(accounts.len());
+        for account in accounts {
+            require_keys_eq!(*account.owner, crate::ID, HostError::InvalidCampaign);
+            let campaign = CampaignAccount::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+            // The same campaign must not boost rewards twice
+            require!(
+                !campaigns.iter().any(|other| other.id == campaign.id),
+                HostError::InvalidCampaign
+            );
+            campaigns.push(campaign);
+        }
+        Ok(campaigns)
+    }
+}
+
+/** Weighted time of `schedule` over `time_from` to `time_to` including campaign bonuses */
+pub fn boosted_time(
+    schedule: &EmissionSchedule,
+    campaigns: &[CampaignAccount],
+    time_from: u64,
+    time_to: u64,
+) -> Result<u64> {
+    campaigns.iter().try_fold(
+        schedule.weighted_time(time_from, time_to)?,
+        |total: u64, campaign| {
+            total
+                .checked_add(campaign.bonus_time(schedule, time_from, time_to)?)
+                .ok_or(error!(MathError::Overflow))
+        },
+    )
+}
+
+/** Scheduled multiplier at `time_now` including bonuses of active campaigns */
+pub fn boosted_multiplier_bps(
+    schedule: &EmissionSchedule,
+    campaigns: &[CampaignAccount],
+    time_now: u64,
+) -> Result<u64> {
+    let multiplier: u64 = schedule.multiplier_bps(time_now)?;
+    campaigns
+        .iter()
+        .filter(|campaign| campaign.is_active(time_now))
+        .try_fold(multiplier, |total: u64, campaign| {
+            total
+                .checked_add(campaign.bonus(multiplier)?)
+                .ok_or(error!(MathError::Overflow))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn campaign(time_start: u64, time_end: u64, multiplier_bps: u64) -> CampaignAccount {
+        CampaignAccount {
+            version: CampaignAccount::LATEST_VERSION,
+            id: 0,
+            time_start,
+            time_end,
+            multiplier_bps,
+        }
+    }
+
+    #[test]
+    fn rejects_multipliers_above_maximum() {
+        let params = CampaignParams {
+            time_start: 10,
+            time_end: 20,
+            multiplier_bps: MAX_MULTIPLIER_BPS + 1,
+        };
+        assert!(campaign(0, 0, 0).configure(0, params, 0).is_err());
+    }
+
+    #[test]
+    fn boosts_time_within_window() {
+        let schedule = EmissionSchedule::default();
+        let campaigns = [campaign(10, 20, 2 * BPS_DENOMINATOR)];
+        // The campaign doubles the ten seconds inside its window
+        let weighted: u64 = boosted_time(&schedule, &campaigns, 0, 30).unwrap();
+        assert_eq!(weighted, 40 * BPS_DENOMINATOR);
+        let multiplier: u64 = boosted_multiplier_bps(&schedule, &campaigns, 15).unwrap();
+        assert_eq!(multiplier, 2 * BPS_DENOMINATOR);
+    }
+
+    #[test]
+    fn closes_after_the_settlement_period() {
+        let campaign = campaign(10, 20, 2 * BPS_DENOMINATOR);
+        assert!(!campaign.is_closable(20));
+        assert!(!campaign.is_closable(20 + MATURATION_PERIOD - 1));
+        assert!(campaign.is_closable(20 + MATURATION_PERIOD));
+    }
+}
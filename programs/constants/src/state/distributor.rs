@@ -0,0 +1,187 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
+use crate::{constants::*, errors::DistributionError};
+
+/**
+ Admin published Merkle root of retroactive bonus rewards.
+ Each leaf hashes the `LEAF_PREFIX`, recipient index, member
+ wallet and entry token amount, and the claimed bitmap is
+ indexed by the recipient index so each allocation is claimed
+ only once.
+ */
+#[account]
+pub struct DistributorAccount {
+    /** Schema version from v0 up to v255. Defaults to the `LATEST_VERSION` constant. */
+    pub version: u8,
+    /** Sequential identifier assigned from the host `total_distributions` counter */
+    pub id: u64,
+    /** Root of the Merkle tree of (index, member, amount) allocations */
+    pub root: [u8; 32],
+    /** Entry tokens allocated across all recipients */
+    pub total_amount: u64,
+    /** Entry tokens claimed so far */
+    pub total_claimed: u64,
+    /** Number of allocations in the tree */
+    pub total_recipients: u32,
+    /** Bit per recipient index set once claimed */
+    pub claimed: Vec<u8>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone)]
+pub struct DistributionParams {
+    pub root: [u8; 32],
+    pub total_amount: u64,
+    pub total_recipients: u32,
+}
+
+impl DistributorAccount {
+    pub const LATEST_VERSION: u8 = 1;
+    /** Domain separator keeping leaves distinct from 64 byte internal nodes */
+    pub const LEAF_PREFIX: u8 = 0x00;
+
+    pub const fn space(total_recipients: u32) -> usize {
+        ANCHOR_DISCRIMINATOR_SIZE
+            + 1 // version
+            + 8 // id
+            + 32 // root
+            + 8 // total_amount
+            + 8 // total_claimed
+            + 4 // total_recipients
+            + ANCHOR_VECTOR_SIZE
+            + (total_recipients as usize).div_ceil(8)
+    }
+
+    pub fn configure(&mut self, id: u64, params: DistributionParams) -> Result<()> {
+        require!(
+            params.total_recipients > 0
+                && params.total_recipients as usize <= MAX_DISTRIBUTION_RECIPIENTS,
+            DistributionError::InvalidRecipients
+        );
+        self.version = Self::LATEST_VERSION;
+        self.id = id;
+        self.root = params.root;
+        self.total_amount = params.total_amount;
+        self.total_recipients = params.total_recipients;
+        self.claimed = vec![0; (params.total_recipients as usize).div_ceil(8)];
+        Ok(())
+    }
+
+    /** Verifies the allocation of `amount` to `member` and marks it claimed */
+    pub fn claim(
+        &mut self,
+        index: u32,
+        member: Pubkey,
+        amount: u64,
+        proof: &[[u8; 32]],
+    ) -> Result<()> {
+        require!(
+            index < self.total_recipients,
+            DistributionError::InvalidIndex
+        );
+        let (byte, mask) = (index as usize / 8, 1u8 << (index % 8));
+        require!(
+            self.claimed[byte] & mask == 0,
+            DistributionError::AlreadyClaimed
+        );
+        require!(
+            verify_proof(proof, self.root, Self::leaf(index, member, amount)),
+            DistributionError::InvalidProof
+        );
+        // Guard against a root allocating more than published
+        require!(
+            self.total_claimed + amount <= self.total_amount,
+            DistributionError::ExceedsAllocation
+        );
+        self.claimed[byte] |= mask;
+        self.total_claimed += amount;
+        Ok(())
+    }
+
+    /** Hashes the allocation of `amount` to `member` at `index` */
+    pub fn leaf(index: u32, member: Pubkey, amount: u64) -> [u8; 32] {
+        keccak::hashv(&[
+            &[Self::LEAF_PREFIX],
+            &index.to_le_bytes(),
+            member.as_ref(),
+            &amount.to_le_bytes(),
+        ])
+        .0
+    }
+}
+
+/** Verifies a Merkle proof hashing each sibling pair in sorted order */
+pub fn verify_proof(proof: &[[u8; 32]], root: [u8; 32], leaf: [u8; 32]) -> bool {
+    let computed: [u8; 32] = proof.iter().fold(leaf, |node, sibling| {
+        if node <= *sibling {
+            keccak::hashv(&[&node, sibling]).0
+        } else {
+            keccak::hashv(&[sibling, &node]).0
+        }
+    });
+    computed == root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(left: [u8; 32], right: [u8; 32]) -> [u8; 32] {
+        if left <= right {
+            keccak::hashv(&[&left, &right]).0
+        } else {
+            keccak::hashv(&[&right, &left]).0
+        }
+    }
+
+    fn distributor(root: [u8; 32], total_amount: u64) -> DistributorAccount {
+        let mut distributor = DistributorAccount {
+            version: 0,
+            id: 0,
+            root: [0; 32],
+            total_amount: 0,
+            total_claimed: 0,
+            total_recipients: 0,
+            claimed: Vec::new(),
+        };
+        let params = DistributionParams {
+            root,
+            total_amount,
+            total_recipients: 3,
+        };
+        distributor.configure(0, params).unwrap();
+        distributor
+    }
+
+    #[test]
+    fn verifies_sorted_pair_proofs() {
+        let members: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        let leaves: Vec<[u8; 32]> = (0..3)
+            .map(|index| DistributorAccount::leaf(index, members[index as usize], 100))
+            .collect();
+        let branch: [u8; 32] = node(leaves[0], leaves[1]);
+        let root: [u8; 32] = node(branch, leaves[2]);
+        let proofs: [Vec<[u8; 32]>; 3] = [
+            vec![leaves[1], leaves[2]],
+            vec![leaves[0], leaves[2]],
+            vec![branch],
+        ];
+        for (leaf, proof) in leaves.iter().zip(proofs.iter()) {
+            assert!(verify_proof(proof, root, *leaf));
+        }
+        // Proofs don't verify a leaf at another position
+        assert!(!verify_proof(&proofs[2], root, leaves[0]));
+
+        let mut distributor = distributor(root, 250);
+        assert!(distributor.claim(1, members[0], 100, &proofs[1]).is_err());
+        distributor.claim(0, members[0], 100, &proofs[0]).unwrap();
+        // Allocations are claimed only once
+        assert!(distributor.claim(0, members[0], 100, &proofs[0]).is_err());
+        distributor.claim(2, members[2], 100, &proofs[2]).unwrap();
+        // The root allocates more than published
+        assert_eq!(
+            distributor.claim(1, members[1], 100, &proofs[1]),
+            Err(DistributionError::ExceedsAllocation.into())
+        );
+        assert_eq!(distributor.total_claimed, 200);
+    }
+}
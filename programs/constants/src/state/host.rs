@@ -1,36 +1,278 @@
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::{program::invoke, system_instruction::*};
-use crate::{constants::*, errors::*};
-use solana_program::{program_pack::IsInitialized, pubkey};
+use crate::{
+    constants::*,
+    errors::HostError,
+    math::{BPS_DENOMINATOR, MAX_MULTIPLIER_BPS},
+    rewards::{RewardModel, RewardModelKind},
+    state::schedule::EmissionSchedule,
+};
+use solana_program::pubkey;
 
 #[account]
 pub struct HostAccount {
     /** Schema version from v0 up to v255. Defaults to the `LATEST_VERSION` constant. */
     pub version: u8,
+    /** Decimals of the locked token mint */
+    pub mint_decimals: u8,
     /** Monthly slot locking period */
     pub maturation_period: u64,
     /** Ratio of entries granted to tokens locked */
     pub reward_factor: u64,
+    /** Entry tokens granted on the first deposit of a member */
+    pub activation_reward: u64,
 
     pub total_members: u64,
 
     pub total_locked: u64,
+    /** Number of campaigns created, assigning sequential campaign identifiers */
+    pub total_campaigns: u64,
+    /** Number of partner reward streams created, assigning sequential stream identifiers */
+    pub total_streams: u64,
+    /** Number of Merkle distributions published, assigning sequential identifiers */
+    pub total_distributions: u64,
+    /** Active `RewardModelKind` determining member entry accrual */
+    pub reward_model: u8,
+    /** Fixed-point entries emitted per second by the pro-rata model */
+    pub emission_rate: u64,
+    /** Fixed-point entries accrued per locked token by the pro-rata model */
+    pub reward_per_token: u128,
+    /** Date the reward per token accumulator was last updated */
+    pub time_accrued: u64,
+    /** Schedule scaling the base reward factor over time */
+    pub schedule: EmissionSchedule,
+    /** Entry tokens claimable across all members per epoch, zero disables the cap */
+    pub epoch_emission_cap: u64,
+    /** Date the current emission epoch started */
+    pub time_epoch: u64,
+    /** Entry tokens claimed during the current emission epoch */
+    pub epoch_emitted: u64,
+    /** Reward multipliers in basis points indexed by `MemberTier` */
+    pub tier_multipliers_bps: [u64; TOTAL_TIERS],
+    /** Loyalty bonus in basis points gained per epoch of uninterrupted locking */
+    pub loyalty_bps_per_epoch: u64,
+    /** Upper bound of the loyalty bonus in basis points */
+    pub max_loyalty_bps: u64,
+    /** Number of earliest members receiving the early adopter bonus, zero disables */
+    pub early_adopter_limit: u64,
+    /** Reward multiplier in basis points applied to early adopters */
+    pub early_adopter_multiplier_bps: u64,
+    /** Period since membership creation the early adopter bonus applies */
+    pub early_adopter_period: u64,
+    /** Period claimed rewards vest over before they are mintable, zero mints immediately */
+    pub vesting_period: u64,
+    /** Epochs rewards may remain unclaimed before being forfeited, zero disables */
+    pub reward_expiry_epochs: u64,
+    /** Key signing bonus reward vouchers, the default key disables vouchers */
+    pub voucher_signer: Pubkey,
+    /** Share in basis points of claimed rewards credited to the referrer */
+    pub referral_bps: u64,
+    /** Release amount above which the extended unbonding period applies, zero disables */
+    pub large_release_threshold: u64,
+    /** Additional unbonding period applied to large releases */
+    pub large_release_period: u64,
+    /** Period released tokens remain collectable before being re-locked, zero disables */
+    pub release_collection_window: u64,
+    /** Number of campaigns created and not yet closed */
+    pub open_campaigns: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone)]
+pub struct HostParams {
+    pub activation_reward: u64,
+    pub reward_model: u8,
+    pub emission_rate: u64,
+    pub epoch_emission_cap: u64,
+    pub tier_multipliers_bps: [u64; TOTAL_TIERS],
+    pub loyalty_bps_per_epoch: u64,
+    pub max_loyalty_bps: u64,
+    pub early_adopter_limit: u64,
+    pub early_adopter_multiplier_bps: u64,
+    pub early_adopter_period: u64,
+    pub vesting_period: u64,
+    pub reward_expiry_epochs: u64,
+    pub voucher_signer: Pubkey,
+    pub referral_bps: u64,
+    pub large_release_threshold: u64,
+    pub large_release_period: u64,
+    pub release_collection_window: u64,
 }
 
 impl HostAccount {
     pub const LATEST_VERSION: u8 = 1;
 
     pub const MINT: Pubkey = pubkey!("M1NTCgX3PG6hjpf4RAa7gjGrxz8rv4WeKzpW5cu31f8");
+
+    pub const fn space() -> usize {
+        ANCHOR_DISCRIMINATOR_SIZE + std::mem::size_of::<HostAccount>()
+    }
+
+    pub fn configure(&mut self, params: HostParams) -> Result<()> {
+        // Stacked multipliers are bounded so member rewards stay within range
+        require!(
+            params
+                .tier_multipliers_bps
+                .iter()
+                .all(|multiplier| *multiplier <= MAX_MULTIPLIER_BPS)
+                && params.early_adopter_multiplier_bps <= MAX_MULTIPLIER_BPS
+                && params.max_loyalty_bps <= MAX_MULTIPLIER_BPS - BPS_DENOMINATOR,
+            HostError::InvalidMultiplier
+        );
+        // Referrers can't be credited more than the referred member claimed
+        require!(
+            params.referral_bps <= BPS_DENOMINATOR,
+            HostError::InvalidReferralShare
+        );
+        // Unclaimed rewards are tracked in one bucket per epoch until they expire
+        require!(
+            params.reward_expiry_epochs < MAX_REWARD_BUCKETS as u64,
+            HostError::InvalidRewardExpiry
+        );
+        self.activation_reward = params.activation_reward;
+        self.reward_model = RewardModelKind::try_from(params.reward_model)?.to_u8();
+        self.emission_rate = params.emission_rate;
+        self.epoch_emission_cap = params.epoch_emission_cap;
+        self.tier_multipliers_bps = params.tier_multipliers_bps;
+        self.loyalty_bps_per_epoch = params.loyalty_bps_per_epoch;
+        self.max_loyalty_bps = params.max_loyalty_bps;
+        self.early_adopter_limit = params.early_adopter_limit;
+        self.early_adopter_multiplier_bps = params.early_adopter_multiplier_bps;
+        self.early_adopter_period = params.early_adopter_period;
+        self.vesting_period = params.vesting_period;
+        self.reward_expiry_epochs = params.reward_expiry_epochs;
+        self.voucher_signer = params.voucher_signer;
+        self.referral_bps = params.referral_bps;
+        self.large_release_threshold = params.large_release_threshold;
+        self.large_release_period = params.large_release_period;
+        self.release_collection_window = params.release_collection_window;
+        Ok(())
+    }
+
+    pub fn reward_model(&self) -> Result<&'static dyn RewardModel> {
+        Ok(RewardModelKind::try_from(self.reward_model)?.model())
+    }
+
+    /** Entry tokens still claimable during the epoch containing `time_now` */
+    pub fn remaining_emission(&self, time_now: u64) -> u64 {
+        if self.epoch_emission_cap == 0 {
+            return u64::MAX;
+        }
+        if time_now >= self.time_epoch + MATURATION_PERIOD {
+            return self.epoch_emission_cap;
+        }
+        self.epoch_emission_cap.saturating_sub(self.epoch_emitted)
+    }
+
+    /** Allocates up to `rewards` from the epoch budget, returning the amount granted */
+    pub fn allocate_emission(&mut self, rewards: u64, time_now: u64) -> u64 {
+        // Start a new budget once the epoch has elapsed
+        if time_now >= self.time_epoch + MATURATION_PERIOD {
+            let elapsed_epochs: u64 = (time_now - self.time_epoch) / MATURATION_PERIOD;
+            self.time_epoch += elapsed_epochs * MATURATION_PERIOD;
+            self.epoch_emitted = 0;
+        }
+        let granted: u64 = rewards.min(self.remaining_emission(time_now));
+        self.epoch_emitted += granted;
+        granted
+    }
+
+    /** Reward multiplier in basis points of the member `tier` */
+    pub fn tier_multiplier_bps(&self, tier: u8) -> u64 {
+        self.tier_multipliers_bps[tier as usize]
+    }
+
+    /** Checks if the member numbered `member_number` qualifies as an early adopter */
+    pub fn is_early_adopter(&self, member_number: u64) -> bool {
+        member_number > 0 && member_number <= self.early_adopter_limit
+    }
+
+    /** Checks if a release of `amount` requires the extended unbonding period */
+    pub fn is_large_release(&self, amount: u64) -> bool {
+        self.large_release_threshold > 0 && amount > self.large_release_threshold
+    }
+
+    /** Checks if tokens released at `time_released` were left uncollected past the window */
+    pub fn is_release_expired(&self, time_released: u64, time_now: u64) -> bool {
+        self.release_collection_window > 0
+            && time_now >= time_released + self.release_collection_window
+    }
 }
 
 impl Default for HostAccount {
     fn default() -> Self {
         HostAccount {
             version: Self::LATEST_VERSION,
+            mint_decimals: 9,
             maturation_period: 3, // 31557600 / 12;
             reward_factor: 100,
+            activation_reward: ACTIVATION_REWARD,
             total_members: 0,
             total_locked: 0,
+            total_campaigns: 0,
+            total_streams: 0,
+            total_distributions: 0,
+            reward_model: RewardModelKind::FixedEpoch.to_u8(),
+            emission_rate: 0,
+            reward_per_token: 0,
+            time_accrued: 0,
+            schedule: EmissionSchedule::default(),
+            epoch_emission_cap: 0,
+            time_epoch: 0,
+            epoch_emitted: 0,
+            tier_multipliers_bps: [BPS_DENOMINATOR; TOTAL_TIERS],
+            loyalty_bps_per_epoch: 0,
+            max_loyalty_bps: 0,
+            early_adopter_limit: 0,
+            early_adopter_multiplier_bps: BPS_DENOMINATOR,
+            early_adopter_period: 0,
+            vesting_period: 0,
+            reward_expiry_epochs: 0,
+            voucher_signer: Pubkey::default(),
+            referral_bps: 0,
+            large_release_threshold: 0,
+            large_release_period: 0,
+            release_collection_window: 0,
+            open_campaigns: 0,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn caps_emission_within_the_epoch() {
+        let mut host = HostAccount {
+            epoch_emission_cap: 100,
+            ..HostAccount::default()
+        };
+        assert_eq!(host.allocate_emission(60, 0), 60);
+        // The cap is hit partway through the claim
+        assert_eq!(host.allocate_emission(60, 10), 40);
+        assert_eq!(host.remaining_emission(10), 0);
+        assert_eq!(host.allocate_emission(1, 10), 0);
+        // Without a cap every claim is granted in full
+        host.epoch_emission_cap = 0;
+        assert_eq!(host.remaining_emission(10), u64::MAX);
+        assert_eq!(host.allocate_emission(1_000, 10), 1_000);
+    }
+
+    #[test]
+    fn rolls_emission_over_to_the_next_epoch() {
+        let mut host = HostAccount {
+            epoch_emission_cap: 100,
+            ..HostAccount::default()
+        };
+        assert_eq!(host.allocate_emission(100, 0), 100);
+        assert_eq!(host.remaining_emission(MATURATION_PERIOD - 1), 0);
+        // The budget resets as soon as the epoch elapsed
+        assert_eq!(host.remaining_emission(MATURATION_PERIOD), 100);
+        assert_eq!(host.allocate_emission(30, MATURATION_PERIOD + 5), 30);
+        assert_eq!(host.time_epoch, MATURATION_PERIOD);
+        assert_eq!(host.epoch_emitted, 30);
+        // Skipped epochs align the budget to the epoch containing the claim
+        assert_eq!(host.allocate_emission(30, 3 * MATURATION_PERIOD + 5), 30);
+        assert_eq!(host.time_epoch, 3 * MATURATION_PERIOD);
+        assert_eq!(host.epoch_emitted, 30);
+    }
+}
@@ -0,0 +1,197 @@
+use anchor_lang::{prelude::*, Discriminator};
+use crate::{
+    constants::ANCHOR_DISCRIMINATOR_SIZE,
+    errors::{MathError, MemberError},
+    slots::Transaction,
+    state::{host::HostAccount, subscription::SubscriptionAccount},
+};
+
+/** Slot layout of subscriptions created before schema version 2 */
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Debug)]
+pub enum TransactionV1 {
+    Deposit {
+        amount: u64,
+        time_created: u64,
+        time_matured: u64,
+    },
+    Withdraw {
+        amount: u64,
+        time_released: u64,
+    },
+}
+
+/**
+ Layout of subscriptions created before schema version 2,
+ kept to migrate existing members. Later fields are appended
+ after `slots`, so legacy accounts can't be deserialized as
+ the current `SubscriptionAccount` until migrated.
+ */
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Debug)]
+pub struct SubscriptionAccountV1 {
+    pub version: u8,
+    pub tier: u8,
+    pub status: u8,
+    pub total_amount: u64,
+    pub total_matured: u64,
+    pub total_released: u64,
+    pub total_rewards: u64,
+    pub time_created: u64,
+    pub time_rewarded: u64,
+    pub slots: Vec<TransactionV1>,
+}
+
+impl From<TransactionV1> for Transaction {
+    fn from(slot: TransactionV1) -> Self {
+        match slot {
+            TransactionV1::Deposit {
+                amount,
+                time_created,
+                time_matured,
+            } => Transaction::Deposit {
+                amount,
+                time_created,
+                time_matured,
+            },
+            TransactionV1::Withdraw {
+                amount,
+                time_released,
+            } => Transaction::Withdraw {
+                amount,
+                time_released,
+                delayed: false,
+            },
+        }
+    }
+}
+
+impl SubscriptionAccountV1 {
+    /** Deserializes a legacy subscription, rejecting accounts already migrated */
+    pub fn load(data: &[u8]) -> Result<Self> {
+        require!(
+            data.len() > ANCHOR_DISCRIMINATOR_SIZE
+                && data[..ANCHOR_DISCRIMINATOR_SIZE] == SubscriptionAccount::DISCRIMINATOR,
+            anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+        );
+        let mut legacy: &[u8] = &data[ANCHOR_DISCRIMINATOR_SIZE..];
+        let subscription = Self::deserialize(&mut legacy)?;
+        require!(
+            subscription.version < SubscriptionAccount::LATEST_VERSION,
+            MemberError::AccountMigrated
+        );
+        Ok(subscription)
+    }
+
+    /**
+     Converts to the latest layout, joining the host matured
+     balance at the current checkpoint. Legacy members are
+     numbered in migration order, so early adopters among them
+     can still qualify for the bonus.
+     */
+    pub fn migrate(self, host: &mut HostAccount, time_now: u64) -> Result<SubscriptionAccount> {
+        // Settle global rewards before the matured balance joins the pro-rata share
+        host.reward_model()?.update(host, time_now)?;
+        host.total_locked = host
+            .total_locked
+            .checked_add(self.total_matured)
+            .ok_or(MathError::Overflow)?;
+        // Assign the next sequential member number
+        host.total_members += 1;
+        let mut subscription = SubscriptionAccount {
+            version: SubscriptionAccount::LATEST_VERSION,
+            tier: self.tier,
+            status: self.status,
+            total_amount: self.total_amount,
+            total_matured: self.total_matured,
+            total_released: self.total_released,
+            time_created: self.time_created,
+            time_rewarded: self.time_rewarded,
+            slots: self.slots.into_iter().map(Transaction::from).collect(),
+            reward_per_token_paid: host.reward_per_token,
+            member_number: host.total_members,
+            ..SubscriptionAccount::default()
+        };
+        // Released tokens are tracked per slot, starting their collection window now
+        if self.total_released > 0 {
+            subscription.slots.push(Transaction::Withdraw {
+                amount: self.total_released,
+                time_released: time_now,
+                delayed: false,
+            });
+        }
+        // Unclaimed rewards start expiring from the migration
+        subscription.credit_rewards(self.total_rewards, time_now);
+        Ok(subscription)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn migrates_legacy_subscription() {
+        let legacy = SubscriptionAccountV1 {
+            version: 0,
+            tier: 1,
+            status: 1,
+            total_amount: 300,
+            total_matured: 100,
+            total_released: 50,
+            total_rewards: 7,
+            time_created: 10,
+            time_rewarded: 20,
+            slots: vec![TransactionV1::Withdraw {
+                amount: 150,
+                time_released: 40,
+            }],
+        };
+        let mut data: Vec<u8> = SubscriptionAccount::DISCRIMINATOR.to_vec();
+        legacy.serialize(&mut data).unwrap();
+
+        let mut host = HostAccount {
+            total_locked: 1_000,
+            total_members: 4,
+            early_adopter_limit: 5,
+            ..HostAccount::default()
+        };
+        let subscription = SubscriptionAccountV1::load(&data)
+            .unwrap()
+            .migrate(&mut host, 30)
+            .unwrap();
+        assert_eq!(subscription.version, SubscriptionAccount::LATEST_VERSION);
+        // The matured balance joins the host total
+        assert_eq!(host.total_locked, 1_100);
+        // Legacy members are numbered like new members and can be early adopters
+        assert_eq!(subscription.member_number, 5);
+        assert_eq!(host.total_members, 5);
+        assert!(host.is_early_adopter(subscription.member_number));
+        assert_eq!(subscription.total_amount, 300);
+        assert_eq!(subscription.total_rewards, 7);
+        assert!(matches!(
+            subscription.slots[0],
+            Transaction::Withdraw {
+                amount: 150,
+                time_released: 40,
+                delayed: false
+            }
+        ));
+        assert!(matches!(
+            subscription.slots[1],
+            Transaction::Withdraw {
+                amount: 50,
+                time_released: 30,
+                ..
+            }
+        ));
+
+        // The host total can't overflow
+        host.total_locked = u64::MAX;
+        let legacy = SubscriptionAccountV1::load(&data).unwrap();
+        assert!(legacy.migrate(&mut host, 30).is_err());
+
+        // Migrated accounts can't be migrated again
+        let mut data: Vec<u8> = Vec::new();
+        subscription.try_serialize(&mut data).unwrap();
+        assert!(SubscriptionAccountV1::load(&data).is_err());
+    }
+}
@@ -1,14 +1,34 @@
 use anchor_lang::prelude::*;
 
+pub use buckets::*;
+pub use campaign::*;
+pub use distributor::*;
+pub use host::*;
+pub use migration::*;
+pub use recovery::*;
+pub use schedule::*;
 pub use slots::*;
 pub use status::*;
+pub use stream::*;
 pub use subscription::*;
 pub use tiers::*;
+pub use timelock::*;
+pub use voucher::*;
 
+pub mod buckets;
+pub mod campaign;
+pub mod distributor;
+pub mod host;
+pub mod migration;
+pub mod recovery;
+pub mod schedule;
 pub mod slots;
 pub mod status;
+pub mod stream;
 pub mod subscription;
 pub mod tiers;
+pub mod timelock;
+pub mod voucher;
 
 #[account]
 #[derive(Debug)]
@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::RecoveryError};
+
+#[account]
+pub struct RecoveryAccount {
+    /** Schema version from v0 up to v255. Defaults to the `LATEST_VERSION` constant. */
+    pub version: u8,
+    /** Wallet currently bound to the subscription */
+    pub owner: Pubkey,
+    /** Number of guardian approvals required to recover the subscription */
+    pub threshold: u8,
+    /** Keys permitted to propose and approve a recovery */
+    pub guardians: Vec<Pubkey>,
+    /** Wallet proposed to take ownership, default key when nothing is pending */
+    pub new_owner: Pubkey,
+    /** Bitmask of guardian indexes approving the pending recovery */
+    pub approvals: u8,
+    /** Date the pending recovery was proposed */
+    pub time_requested: u64,
+}
+
+impl RecoveryAccount {
+    pub const LATEST_VERSION: u8 = 1;
+
+    pub const fn space() -> usize {
+        ANCHOR_DISCRIMINATOR_SIZE
+            + 1 // version
+            + 32 // owner
+            + 1 // threshold
+            + ANCHOR_VECTOR_SIZE
+            + (32 * MAX_GUARDIANS)
+            + 32 // new_owner
+            + 1 // approvals
+            + 8 // time_requested
+    }
+
+    pub fn set_guardians(&mut self, guardians: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        // Guardians can't be replaced while a recovery is in flight
+        require!(!self.is_pending(), RecoveryError::RecoveryPending);
+        require!(
+            !guardians.is_empty() && guardians.len() <= MAX_GUARDIANS,
+            RecoveryError::InvalidGuardians
+        );
+        require!(
+            threshold > 0 && threshold as usize <= guardians.len(),
+            RecoveryError::InvalidThreshold
+        );
+        // Reject the owner and duplicate keys from the guardian set
+        for (index, guardian) in guardians.iter().enumerate() {
+            require!(
+                *guardian != self.owner && !guardians[..index].contains(guardian),
+                RecoveryError::InvalidGuardians
+            );
+        }
+
+        self.guardians = guardians;
+        self.threshold = threshold;
+        Ok(())
+    }
+
+    pub fn propose(&mut self, guardian: Pubkey, new_owner: Pubkey, time_now: u64) -> Result<()> {
+        require!(!self.is_pending(), RecoveryError::RecoveryPending);
+        require!(
+            new_owner != Pubkey::default() && new_owner != self.owner,
+            RecoveryError::OwnerMismatch
+        );
+        let index = self.guardian_index(&guardian)?;
+        // Start the veto window with the proposing guardian's approval
+        self.new_owner = new_owner;
+        self.approvals = 1 << index;
+        self.time_requested = time_now;
+        Ok(())
+    }
+
+    pub fn approve(&mut self, guardian: Pubkey, new_owner: Pubkey) -> Result<()> {
+        require!(self.is_pending(), RecoveryError::NoRecoveryPending);
+        // Guardians approve the wallet they were shown, not whatever is pending
+        require!(self.new_owner == new_owner, RecoveryError::OwnerMismatch);
+        let index = self.guardian_index(&guardian)?;
+        self.approvals |= 1 << index;
+        Ok(())
+    }
+
+    pub fn veto(&mut self) -> Result<()> {
+        require!(self.is_pending(), RecoveryError::NoRecoveryPending);
+        self.clear();
+        Ok(())
+    }
+
+    pub fn execute(&mut self, time_now: u64) -> Result<()> {
+        require!(self.is_pending(), RecoveryError::NoRecoveryPending);
+        require!(
+            self.approvals.count_ones() >= self.threshold as u32,
+            RecoveryError::InsufficientApprovals
+        );
+        require!(
+            time_now >= self.time_requested + RECOVERY_DELAY,
+            RecoveryError::RecoveryLocked
+        );
+        self.clear();
+        Ok(())
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.new_owner != Pubkey::default()
+    }
+
+    fn guardian_index(&self, guardian: &Pubkey) -> Result<usize> {
+        self.guardians
+            .iter()
+            .position(|key| key == guardian)
+            .ok_or(error!(RecoveryError::UnknownGuardian))
+    }
+
+    fn clear(&mut self) {
+        self.new_owner = Pubkey::default();
+        self.approvals = 0;
+        self.time_requested = 0;
+    }
+}
@@ -0,0 +1,236 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::{MATURATION_PERIOD, MAX_SCHEDULE_STEPS},
+    errors::HostError,
+    math::{BPS_DENOMINATOR, MAX_MULTIPLIER_BPS},
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq)]
+pub enum ScheduleKind {
+    Constant, // Base reward factor applies indefinitely
+    Step,     // Multiplier changes at admin defined timestamps
+    Decay,    // Multiplier decays exponentially every epoch
+}
+
+impl TryFrom<u8> for ScheduleKind {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self> {
+        match value {
+            0 => Ok(ScheduleKind::Constant),
+            1 => Ok(ScheduleKind::Step),
+            2 => Ok(ScheduleKind::Decay),
+            _ => err!(HostError::InvalidSchedule),
+        }
+    }
+}
+
+impl ScheduleKind {
+    pub fn to_u8(&self) -> u8 {
+        match self {
+            ScheduleKind::Constant => 0,
+            ScheduleKind::Step => 1,
+            ScheduleKind::Decay => 2,
+        }
+    }
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, Debug, Default)]
+pub struct ScheduleStep {
+    /** Date the multiplier takes effect, zero marks an unused step */
+    pub time_start: u64,
+    /** Reward multiplier in basis points of the base reward factor */
+    pub multiplier_bps: u64,
+}
+
+/**
+ Admin defined emission schedule scaling the base reward
+ factor over time, either by step halvings at given dates or
+ by exponential decay each epoch since `time_start`, so early
+ members are rewarded more and long run supply is bounded.
+ */
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, Debug, Default)]
+pub struct EmissionSchedule {
+    /** Schedule `ScheduleKind` stored as u8 */
+    pub kind: u8,
+    /** Date the decay schedule starts from */
+    pub time_start: u64,
+    /** Multiplier reduction per epoch of the decay schedule in basis points */
+    pub decay_bps: u64,
+    /** Ascending multiplier steps of the step schedule */
+    pub steps: [ScheduleStep; MAX_SCHEDULE_STEPS],
+}
+
+impl EmissionSchedule {
+    pub fn validate(&self) -> Result<()> {
+        match ScheduleKind::try_from(self.kind)? {
+            ScheduleKind::Constant => {}
+            ScheduleKind::Step => {
+                let steps = self.active_steps();
+                require!(!steps.is_empty(), HostError::InvalidSchedule);
+                require!(
+                    steps
+                        .iter()
+                        .all(|step| step.multiplier_bps <= MAX_MULTIPLIER_BPS),
+                    HostError::InvalidMultiplier
+                );
+                // Steps must be sorted with unused steps trailing
+                require!(
+                    steps
+                        .windows(2)
+                        .all(|pair| pair[0].time_start < pair[1].time_start)
+                        && self.steps[steps.len()..]
+                            .iter()
+                            .all(|step| step.time_start == 0),
+                    HostError::InvalidSchedule
+                );
+            }
+            ScheduleKind::Decay => {
+                require!(
+                    self.decay_bps > 0 && self.decay_bps < BPS_DENOMINATOR,
+                    HostError::InvalidSchedule
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /** Reward multiplier in basis points applied at `time_now` */
+    pub fn multiplier_bps(&self, time_now: u64) -> Result<u64> {
+        match ScheduleKind::try_from(self.kind)? {
+            ScheduleKind::Constant => Ok(BPS_DENOMINATOR),
+            ScheduleKind::Step => Ok(self
+                .active_steps()
+                .iter()
+                .rev()
+                .find(|step| step.time_start <= time_now)
+                .map_or(BPS_DENOMINATOR, |step| step.multiplier_bps)),
+            ScheduleKind::Decay => {
+                let epochs: u64 = time_now.saturating_sub(self.time_start) / MATURATION_PERIOD;
+                Ok((0..epochs).fold(BPS_DENOMINATOR, |multiplier, _| self.decay(multiplier)))
+            }
+        }
+    }
+
+    /**
+     Integrates the multiplier over `time_from` to `time_to`,
+     returning elapsed time weighted in basis point seconds.
+     */
+    pub fn weighted_time(&self, time_from: u64, time_to: u64) -> Result<u64> {
+        if time_to <= time_from {
+            return Ok(0);
+        }
+        match ScheduleKind::try_from(self.kind)? {
+            ScheduleKind::Constant => Ok((time_to - time_from) * BPS_DENOMINATOR),
+            ScheduleKind::Step => {
+                let mut weighted: u64 = 0;
+                let mut time_segment: u64 = time_from;
+                // Split the range at every step boundary it crosses
+                for step in self.active_steps() {
+                    if step.time_start <= time_segment || step.time_start >= time_to {
+                        continue;
+                    }
+                    weighted +=
+                        (step.time_start - time_segment) * self.multiplier_bps(time_segment)?;
+                    time_segment = step.time_start;
+                }
+                Ok(weighted + (time_to - time_segment) * self.multiplier_bps(time_segment)?)
+            }
+            ScheduleKind::Decay => {
+                let mut weighted: u64 = 0;
+                let mut time_segment: u64 = time_from;
+                let mut multiplier: u64 = self.multiplier_bps(time_from)?;
+                // Evaluate the decayed multiplier for each elapsed epoch
+                while time_segment < time_to && multiplier > 0 {
+                    let epoch_end: u64 = if time_segment < self.time_start {
+                        self.time_start
+                    } else {
+                        let epochs: u64 = (time_segment - self.time_start) / MATURATION_PERIOD;
+                        self.time_start + (epochs + 1) * MATURATION_PERIOD
+                    };
+                    let time_end: u64 = epoch_end.min(time_to);
+                    weighted += (time_end - time_segment) * multiplier;
+                    if time_end == epoch_end && epoch_end > self.time_start {
+                        multiplier = self.decay(multiplier);
+                    }
+                    time_segment = time_end;
+                }
+                Ok(weighted)
+            }
+        }
+    }
+
+    fn decay(&self, multiplier: u64) -> u64 {
+        multiplier * (BPS_DENOMINATOR - self.decay_bps) / BPS_DENOMINATOR
+    }
+
+    fn active_steps(&self) -> &[ScheduleStep] {
+        let count: usize = self
+            .steps
+            .iter()
+            .take_while(|step| step.time_start > 0)
+            .count();
+        &self.steps[..count]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn step_schedule(multipliers: &[(u64, u64)]) -> EmissionSchedule {
+        let mut schedule = EmissionSchedule {
+            kind: ScheduleKind::Step.to_u8(),
+            ..EmissionSchedule::default()
+        };
+        for (step, (time_start, multiplier_bps)) in multipliers.iter().enumerate() {
+            schedule.steps[step] = ScheduleStep {
+                time_start: *time_start,
+                multiplier_bps: *multiplier_bps,
+            };
+        }
+        schedule
+    }
+
+    #[test]
+    fn rejects_invalid_schedules() {
+        let unknown = EmissionSchedule {
+            kind: 3,
+            ..EmissionSchedule::default()
+        };
+        assert!(unknown.validate().is_err());
+        assert!(step_schedule(&[(10, MAX_MULTIPLIER_BPS + 1)])
+            .validate()
+            .is_err());
+        assert!(step_schedule(&[(20, 5_000), (10, 2_500)])
+            .validate()
+            .is_err());
+        assert!(step_schedule(&[(10, 5_000), (20, 2_500)])
+            .validate()
+            .is_ok());
+    }
+
+    #[test]
+    fn weights_time_across_steps() {
+        let schedule = step_schedule(&[(10, 20_000), (20, 5_000)]);
+        // Base rate before the first step, then each step multiplier
+        let weighted: u64 = schedule.weighted_time(0, 30).unwrap();
+        assert_eq!(weighted, 10 * BPS_DENOMINATOR + 10 * 20_000 + 10 * 5_000);
+    }
+
+    #[test]
+    fn decays_each_epoch() {
+        let schedule = EmissionSchedule {
+            kind: ScheduleKind::Decay.to_u8(),
+            decay_bps: 5_000,
+            ..EmissionSchedule::default()
+        };
+        let multiplier: u64 = schedule.multiplier_bps(MATURATION_PERIOD - 1).unwrap();
+        assert_eq!(multiplier, BPS_DENOMINATOR);
+        let multiplier: u64 = schedule.multiplier_bps(2 * MATURATION_PERIOD).unwrap();
+        assert_eq!(multiplier, 2_500);
+        // Each epoch is weighted by the multiplier it started with
+        let weighted: u64 = schedule.weighted_time(0, 2 * MATURATION_PERIOD).unwrap();
+        assert_eq!(weighted, MATURATION_PERIOD * (BPS_DENOMINATOR + 5_000));
+    }
+}
@@ -11,6 +11,8 @@ pub enum Transaction {
     Withdraw {
         amount: u64,
         time_released: u64,
+        /** Large releases may be cancelled until `time_released` */
+        delayed: bool,
     },
 }
 
@@ -28,4 +30,12 @@ impl Transaction {
             Transaction::Withdraw { time_released, .. } => time_now >= *time_released,
         }
     }
+
+    /** Date the slot matures or its released tokens become available */
+    pub fn time_matured(&self) -> u64 {
+        match self {
+            Transaction::Deposit { time_matured, .. } => *time_matured,
+            Transaction::Withdraw { time_released, .. } => *time_released,
+        }
+    }
 }
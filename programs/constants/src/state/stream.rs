@@ -0,0 +1,183 @@
+use anchor_lang::prelude::*;
+use crate::{constants::*, errors::MathError, math::ACCUMULATOR_PRECISION};
+
+/**
+ Partner funded reward stream emitting `emission_rate` tokens
+ of its own mint per second from a prefunded vault, shared
+ pro-rata across the matured balance checkpointed by its
+ registered members. The stream stops emitting once its
+ funding is exhausted, and pauses while nobody is registered.
+ */
+#[account]
+pub struct StreamAccount {
+    /** Schema version from v0 up to v255. Defaults to the `LATEST_VERSION` constant. */
+    pub version: u8,
+    /** Sequential identifier assigned from the host `total_streams` counter */
+    pub id: u64,
+    /** Wallet that created the stream */
+    pub funder: Pubkey,
+    /** Mint of the streamed reward token */
+    pub mint: Pubkey,
+    /** Streamed token lamports emitted per second */
+    pub emission_rate: u64,
+    /** Fixed-point streamed lamports accrued per locked token lamport */
+    pub reward_per_token: u128,
+    /** Date the reward per token accumulator was last updated */
+    pub time_accrued: u64,
+    /** Streamed token lamports deposited into the vault */
+    pub total_funded: u64,
+    /** Streamed token lamports distributed through the accumulator */
+    pub total_emitted: u64,
+    /** Sum of the matured balances checkpointed by registered members */
+    pub total_registered: u64,
+    /** Emitted lamports forgone by members whose balance shrank, reclaimable by the funder */
+    pub total_unallocated: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone)]
+pub struct StreamParams {
+    pub emission_rate: u64,
+}
+
+/**
+ Per-member stream checkpoint. Rewards accrue on the smaller
+ of the matured balance at the last checkpoint and at claim,
+ so tokens locked after the checkpoint only earn from the
+ next claim onwards.
+ */
+#[account]
+pub struct StreamMemberAccount {
+    /** Schema version from v0 up to v255. Defaults to the `LATEST_VERSION` constant. */
+    pub version: u8,
+    /** Stream the checkpoint belongs to */
+    pub stream: Pubkey,
+    /** Member wallet owning the checkpoint */
+    pub owner: Pubkey,
+    /** Stream reward per token accumulator at the last claim */
+    pub reward_per_token_paid: u128,
+    /** Matured balance of the member at the last claim */
+    pub locked_amount: u64,
+    /** Streamed token lamports claimed by the member */
+    pub total_claimed: u64,
+}
+
+impl StreamAccount {
+    pub const LATEST_VERSION: u8 = 1;
+
+    pub const fn space() -> usize {
+        ANCHOR_DISCRIMINATOR_SIZE + std::mem::size_of::<StreamAccount>()
+    }
+
+    /** Advances the accumulator up to `time_now`, bounded by the remaining funding */
+    pub fn update(&mut self, time_now: u64) -> Result<()> {
+        let time_elapsed: u64 = time_now.saturating_sub(self.time_accrued);
+        let remaining: u128 = (self.total_funded - self.total_emitted) as u128;
+        let emission: u64 =
+            (self.emission_rate as u128 * time_elapsed as u128).min(remaining) as u64;
+        // Emission while no member is registered stays in the vault
+        if self.total_registered > 0 && emission > 0 {
+            let increment: u128 = (emission as u128)
+                .checked_mul(ACCUMULATOR_PRECISION)
+                .ok_or(MathError::Overflow)?
+                / self.total_registered as u128;
+            self.reward_per_token = self
+                .reward_per_token
+                .checked_add(increment)
+                .ok_or(MathError::Overflow)?;
+            self.total_emitted += emission;
+        }
+        self.time_accrued = self.time_accrued.max(time_now);
+        Ok(())
+    }
+}
+
+impl StreamMemberAccount {
+    pub const LATEST_VERSION: u8 = 1;
+
+    pub const fn space() -> usize {
+        ANCHOR_DISCRIMINATOR_SIZE + std::mem::size_of::<StreamMemberAccount>()
+    }
+
+    pub fn is_registered(&self) -> bool {
+        self.stream != Pubkey::default()
+    }
+
+    /** Starts accruing from the current stream checkpoint */
+    pub fn register(
+        &mut self,
+        stream: &mut Account<StreamAccount>,
+        owner: Pubkey,
+        locked_amount: u64,
+    ) {
+        self.version = Self::LATEST_VERSION;
+        self.stream = stream.key();
+        self.owner = owner;
+        self.reward_per_token_paid = stream.reward_per_token;
+        self.locked_amount = locked_amount;
+        stream.total_registered += locked_amount;
+    }
+
+    /** Settles rewards accrued since the last claim, returning the lamports to transfer */
+    pub fn settle(&mut self, stream: &mut StreamAccount, locked_amount: u64) -> Result<u64> {
+        let delta: u128 = stream
+            .reward_per_token
+            .saturating_sub(self.reward_per_token_paid);
+        let rewards: u64 = Self::accrued(self.locked_amount.min(locked_amount), delta)?;
+        // The share of a checkpointed balance no longer locked returns to the funder
+        let unallocated: u64 = Self::accrued(self.locked_amount, delta)? - rewards;
+        stream.total_unallocated += unallocated;
+        stream.total_registered = stream.total_registered - self.locked_amount + locked_amount;
+        self.reward_per_token_paid = stream.reward_per_token;
+        self.locked_amount = locked_amount;
+        self.total_claimed += rewards;
+        Ok(rewards)
+    }
+
+    fn accrued(locked_amount: u64, delta: u128) -> Result<u64> {
+        let rewards: u128 = (locked_amount as u128)
+            .checked_mul(delta)
+            .ok_or(MathError::Overflow)?
+            / ACCUMULATOR_PRECISION;
+        u64::try_from(rewards).map_err(|_| error!(MathError::Overflow))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn returns_forgone_emission_to_funder() {
+        let mut stream = StreamAccount {
+            version: StreamAccount::LATEST_VERSION,
+            id: 0,
+            funder: Pubkey::default(),
+            mint: Pubkey::default(),
+            emission_rate: 10,
+            reward_per_token: 0,
+            time_accrued: 0,
+            total_funded: 1_000,
+            total_emitted: 0,
+            total_registered: 300,
+            total_unallocated: 0,
+        };
+        let mut member = StreamMemberAccount {
+            version: StreamMemberAccount::LATEST_VERSION,
+            stream: Pubkey::default(),
+            owner: Pubkey::default(),
+            reward_per_token_paid: 0,
+            locked_amount: 100,
+            total_claimed: 0,
+        };
+        // A third of the registered balance earns a third of the emission
+        stream.update(30).unwrap();
+        assert_eq!(stream.total_emitted, 300);
+        // Half of the checkpoint was released, so half of its share is unallocated
+        assert_eq!(member.settle(&mut stream, 50).unwrap(), 50);
+        assert_eq!(stream.total_unallocated, 50);
+        assert_eq!(stream.total_registered, 250);
+        // Emission stops once the funding is exhausted
+        stream.update(1_000).unwrap();
+        assert_eq!(stream.total_emitted, 1_000);
+    }
+}
@@ -1,11 +1,35 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::{program::invoke, system_instruction::*};
 use crate::{
-    constants::*, errors::TransferError, slots::Transaction, status::AccountStatus,
-    tiers::MemberTier, utils::*,
+    constants::*,
+    errors::{LockingError, MathError, MemberError, TransferError},
+    events::RewardsForfeitedEvent,
+    math::{apply_bps, carry_rewards, BPS_DENOMINATOR},
+    slots::Transaction,
+    state::{
+        buckets::RewardBuckets, campaign::CampaignAccount, host::HostAccount,
+        timelock::TimelockedAddress,
+    },
+    status::AccountStatus,
+    tiers::MemberTier,
 };
 use solana_program::program_pack::IsInitialized;
 
+/** Projected member balances returned by the `preview_rewards` instruction */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct RewardPreview {
+    /** Unclaimed entry tokens including rewards accrued up to the preview date */
+    pub pending_rewards: u64,
+    /** Entry tokens claimable within the current epoch emission budget */
+    pub claimable_rewards: u64,
+    pub total_matured: u64,
+    pub total_released: u64,
+    /** Date the next pending slot matures, zero when no slots are pending */
+    pub time_next_maturity: u64,
+    /** Projected `MemberTier` stored as u8 */
+    pub tier: u8,
+}
+
 #[account]
 pub struct SubscriptionAccount {
     /** Schema version from v0 up to v255. Defaults to the `LATEST_VERSION` constant. */
@@ -25,34 +49,130 @@ pub struct SubscriptionAccount {
     pub time_created: u64,
     /** Date of the last reward granted to matured tokens */
     pub time_rewarded: u64,
-    /** Collection of locked token deposit slots (one entry per deposit) */
+    /**
+     Collection of locked token deposit slots (one entry per
+     deposit). Matured releases remain until withdrawn or
+     re-locked at the end of their collection window.
+     */
     pub slots: Vec<Transaction>,
+    /** Incremented by every instruction mutating the account */
+    pub sequence: u64,
+    /** Sequential number assigned from the host member counter, starting at one */
+    pub member_number: u64,
+    /** Subscription of the referring member, the default key when not referred */
+    pub referrer: Pubkey,
+    /** Number of members referred */
+    pub total_referrals: u64,
+    /** Entry tokens earned from referred members' claims */
+    pub referral_rewards: u64,
+    /** Unclaimed entry tokens grouped by the epoch they accrued in */
+    pub reward_buckets: RewardBuckets,
+    /** Claimed entry tokens vesting in the current schedule */
+    pub vesting_amount: u64,
+    /** Entry tokens of the current schedule already collected */
+    pub vesting_collected: u64,
+    /** Entry tokens vested by previous schedules and not yet collected */
+    pub vesting_carried: u64,
+    /** Fraction of an entry token carried between accruals, scaled by `REWARD_PRECISION` */
+    pub reward_remainder: u64,
+    /** Host reward per token accumulator at the last accrual */
+    pub reward_per_token_paid: u128,
+    /** Date the current vesting schedule started */
+    pub time_vesting_start: u64,
+    /** Date the current vesting schedule is fully vested */
+    pub time_vesting_end: u64,
+    /** Date the uninterrupted locking streak started, zero when no streak is running */
+    pub time_streak_start: u64,
+    /** Key permitted to claim on behalf of the owner, the default key disables delegation */
+    pub claim_delegate: Pubkey,
+    /** Allows anyone to claim on behalf of the owner */
+    pub permissionless_claim: bool,
+    /** Wallet receiving claimed rewards, changed after the `BENEFICIARY_DELAY` */
+    pub beneficiary: TimelockedAddress,
+    /** Destination of withdrawn tokens, changed after the `WITHDRAWAL_ADDRESS_DELAY` */
+    pub withdrawal_address: TimelockedAddress,
+    /** Previous owner wallet when the subscription was recovered, otherwise the default key */
+    pub recovered_from: Pubkey,
+    /** Entry tokens forfeited after remaining unclaimed past the expiry */
+    pub total_forfeited: u64,
 }
 
 #[constant]
 impl SubscriptionAccount {
-    pub const LATEST_VERSION: u8 = 1;
+    pub const LATEST_VERSION: u8 = 2;
     pub const MAX_SLOTS: usize = 8;
 
-    pub fn claim(&mut self, time_now: u64) -> Result<u64> {
-        // Determine unclaimed rewards
-        let mut rewards: u64 = self.get_unclaimed_rewards(time_now);
-        // Mature existing slots and collect rewards
-        rewards += self.mature_slots(time_now).unwrap();
-        // Update the reward timestamp
-        self.time_rewarded = time_now;
-        self.total_rewards = 0;
+    pub fn claim(
+        &mut self,
+        time_now: u64,
+        host: &mut HostAccount,
+        campaigns: &[CampaignAccount],
+    ) -> Result<u64> {
+        // Mature existing slots and accrue rewards up to now
+        self.mature_slots(time_now, host, campaigns)?;
+        // Collect outstanding rewards within the epoch emission budget
+        let rewards: u64 = host.allocate_emission(self.total_rewards, time_now);
+        // Rewards over budget are deferred to the next epoch
+        self.total_rewards -= rewards;
+        self.reward_buckets.debit(rewards);
         // Return outstanding rewards
         Ok(rewards)
     }
 
-    pub fn lock(&mut self, amount: u64, time_now: u64) -> Result<()> {
+    /**
+     Vests claimed `rewards` linearly over `vesting_period`. The
+     unvested balance of the current schedule is restarted with
+     the new rewards, while its vested part remains collectable.
+     */
+    pub fn vest(&mut self, rewards: u64, vesting_period: u64, time_now: u64) {
+        let vested: u64 = self.vested_amount(time_now);
+        self.vesting_carried += vested - self.vesting_collected;
+        self.vesting_amount = self.vesting_amount - vested + rewards;
+        self.vesting_collected = 0;
+        self.time_vesting_start = time_now;
+        self.time_vesting_end = time_now + vesting_period;
+    }
+
+    /** Collects vested entry tokens, returning the amount to mint */
+    pub fn collect_vested(&mut self, time_now: u64) -> u64 {
+        let amount: u64 = self.collectable_amount(time_now);
+        self.vesting_carried = 0;
+        self.vesting_collected = self.vested_amount(time_now);
+        amount
+    }
+
+    /** Vested entry tokens not yet collected */
+    pub fn collectable_amount(&self, time_now: u64) -> u64 {
+        self.vesting_carried + self.vested_amount(time_now) - self.vesting_collected
+    }
+
+    /** Entry tokens of the current schedule vested by `time_now` */
+    fn vested_amount(&self, time_now: u64) -> u64 {
+        if time_now >= self.time_vesting_end {
+            return self.vesting_amount;
+        }
+        let elapsed: u128 = time_now.saturating_sub(self.time_vesting_start) as u128;
+        let period: u128 = (self.time_vesting_end - self.time_vesting_start) as u128;
+        (self.vesting_amount as u128 * elapsed / period) as u64
+    }
+
+    pub fn lock(
+        &mut self,
+        amount: u64,
+        time_now: u64,
+        host: &mut HostAccount,
+        campaigns: &[CampaignAccount],
+    ) -> Result<()> {
         // Mature existing slots
-        self.mature_slots(time_now);
+        self.mature_slots(time_now, host, campaigns)?;
         // Update locked amount counter
         self.total_amount += amount;
         // Immediately grant entries
-        self.total_rewards += lamports_to_rewards(amount);
+        let rewards: u128 = host
+            .reward_model()?
+            .deposit_rewards(host, campaigns, amount, time_now)?;
+        let rewards: u64 = carry_rewards(rewards, &mut self.reward_remainder)?;
+        self.credit_rewards(rewards, time_now);
         // Allocate a new deposit slot and store
         let new_slot = Transaction::Deposit {
             amount: amount,
@@ -61,14 +181,110 @@ impl SubscriptionAccount {
         }; // TODO: Compress slots
            // Append the deposit slot if not compressed
         self.slots.push(new_slot);
+        // Start the loyalty streak once the member qualifies
+        self.update_streak(time_now, host);
         // Return outstanding rewards
         Ok(())
     }
 
-    pub fn unlock(&mut self, amount: u64, time_now: u64) -> Result<u64> {
-        // Claim outstanding rewards
-        self.claim(time_now).unwrap();
-        let time_next_epoch: u64 = self.time_rewarded + MATURATION_PERIOD;
+    /** Credits the referral share of `rewards` claimed by a referred member */
+    pub fn credit_referral(
+        &mut self,
+        rewards: u64,
+        host: &HostAccount,
+        time_now: u64,
+    ) -> Result<u64> {
+        let reward: u64 = apply_bps(rewards as u128, host.referral_bps)?
+            .try_into()
+            .map_err(|_| error!(MathError::Overflow))?;
+        self.referral_rewards = self
+            .referral_rewards
+            .checked_add(reward)
+            .ok_or(MathError::Overflow)?;
+        self.credit_rewards(reward, time_now);
+        Ok(reward)
+    }
+
+    /** Records a newly referred member, requiring the referrer to be active */
+    pub fn add_referral(&mut self) -> Result<()> {
+        require!(
+            AccountStatus::equals(AccountStatus::Active, self.status),
+            MemberError::InvalidReferrer
+        );
+        self.total_referrals = self
+            .total_referrals
+            .checked_add(1)
+            .ok_or(MathError::Overflow)?;
+        Ok(())
+    }
+
+    /** Checks if `referrer` is the registered referrer, following a recovered subscription */
+    pub fn is_referred_by(&self, referrer: &Account<SubscriptionAccount>) -> bool {
+        if referrer.key() == self.referrer {
+            return true;
+        }
+        referrer.recovered_from != Pubkey::default()
+            && Pubkey::find_program_address(
+                &[SUBSCRIPTION_SEED_PREFIX, referrer.recovered_from.as_ref()],
+                &crate::ID,
+            )
+            .0 == self.referrer
+    }
+
+    /** Runs the claim maturity and reward logic on copies of the accounts at `time_now` */
+    pub fn preview(
+        &self,
+        host: &HostAccount,
+        campaigns: &[CampaignAccount],
+        time_now: u64,
+    ) -> Result<RewardPreview> {
+        let mut subscription: SubscriptionAccount = self.clone();
+        let mut host: HostAccount = host.clone();
+        subscription.mature_slots(time_now, &mut host, campaigns)?;
+        Ok(RewardPreview {
+            pending_rewards: subscription.total_rewards,
+            claimable_rewards: subscription
+                .total_rewards
+                .min(host.remaining_emission(time_now)),
+            total_matured: subscription.total_matured,
+            total_released: subscription.total_released,
+            time_next_maturity: subscription
+                .slots
+                .iter()
+                .filter(|slot| !slot.is_matured(time_now))
+                .map(Transaction::time_matured)
+                .min()
+                .unwrap_or(0),
+            tier: subscription.tier,
+        })
+    }
+
+    /** Activates a pending member, returning the activation reward granted */
+    pub fn activate(&mut self, host: &HostAccount, time_now: u64) -> Option<u64> {
+        if AccountStatus::not(AccountStatus::Pending, self.status) {
+            return None;
+        }
+        self.status = AccountStatus::Active.to_u8();
+        self.credit_rewards(host.activation_reward, time_now);
+        Some(host.activation_reward)
+    }
+
+    /** Moves `amount` of the matured balance into a pending release, returning its slot */
+    pub fn unlock(
+        &mut self,
+        amount: u64,
+        time_now: u64,
+        host: &mut HostAccount,
+        campaigns: &[CampaignAccount],
+    ) -> Result<Transaction> {
+        // Accrue outstanding rewards before the matured balance changes
+        self.mature_slots(time_now, host, campaigns)?;
+        let delayed: bool = host.is_large_release(amount);
+        let mut time_released: u64 = time_now + MATURATION_PERIOD;
+        // Large releases unbond for an extended period
+        if delayed {
+            time_released += host.large_release_period;
+        }
         // Check sufficient token maturity
         require!(
             self.total_matured >= amount,
@@ -76,17 +292,70 @@ impl SubscriptionAccount {
         );
         // Create a pending withdrawal transaction
         let withdrawal = Transaction::Withdraw {
-            time_released: time_next_epoch, // Released after next epoch
+            time_released, // Released one maturation period from now
             amount,
+            delayed,
         };
         // Append the new locked slot if it can't be compress
-        self.slots.push(withdrawal);
+        self.slots.push(withdrawal.clone());
         // We shift balance from locked to liquidity
         self.total_matured -= amount;
-        // Return amount released
+        host.total_locked = host
+            .total_locked
+            .checked_sub(amount)
+            .ok_or(MathError::Overflow)?;
+        // Break the loyalty streak when falling below the tier minimum
+        self.update_streak(time_now, host);
+        // Return the pending release
+        Ok(withdrawal)
+    }
+
+    pub fn cancel_release(
+        &mut self,
+        index: usize,
+        time_now: u64,
+        host: &mut HostAccount,
+        campaigns: &[CampaignAccount],
+    ) -> Result<u64> {
+        let slot = self
+            .slots
+            .get(index)
+            .ok_or(LockingError::IndexOutOfBounds)?;
+        // Only delayed releases still unbonding can be cancelled
+        let amount = match slot {
+            Transaction::Withdraw {
+                amount,
+                delayed: true,
+                ..
+            } if !slot.is_matured(time_now) => *amount,
+            _ => return err!(LockingError::ReleaseNotCancellable),
+        };
+        // Accrue outstanding rewards before the matured balance changes
+        self.accrue(time_now, host, campaigns)?;
+        // Shift the balance back from liquidity to locked
+        self.slots.remove(index);
+        self.total_matured += amount;
+        host.total_locked += amount;
+        self.update_tier(host);
+        self.update_streak(time_now, host);
         Ok(amount)
     }
 
+    /** Fails when the account changed since the client read `expected_sequence` */
+    pub fn check_sequence(&self, expected_sequence: Option<u64>) -> Result<()> {
+        match expected_sequence {
+            Some(sequence) => {
+                require_eq!(self.sequence, sequence, MemberError::StaleSequence);
+                Ok(())
+            }
+            None => Ok(()),
+        }
+    }
+
+    pub fn bump_sequence(&mut self) {
+        self.sequence = self.sequence.wrapping_add(1);
+    }
+
     pub fn realloc<'a>(
         &self,
         target_account: &AccountInfo<'a>,
@@ -117,71 +386,306 @@ impl SubscriptionAccount {
         Ok(())
     }
 
-    pub fn on_withdraw(&mut self, time_now: u64) -> Result<u64> {
+    pub fn on_withdraw(
+        &mut self,
+        time_now: u64,
+        host: &mut HostAccount,
+        campaigns: &[CampaignAccount],
+    ) -> Result<u64> {
         // Mature withdarwal slots for release
-        self.mature_slots(time_now)?; // TODO: Handle error
+        self.mature_slots(time_now, host, campaigns)?; // TODO: Handle error
 
         // Get the updated release amount
         let amount = self.total_released;
-
+        // Remove the collected releases
+        self.slots.retain(|slot| match slot {
+            Transaction::Deposit { .. } => true,
+            Transaction::Withdraw { .. } => !slot.is_matured(time_now),
+        });
         self.total_released = 0;
         self.total_amount -= amount;
 
         Ok(amount)
     }
 
-    pub fn mature_slots(&mut self, time_now: u64) -> Result<u64> {
+    /**
+     Rebinds the subscription recovered from the lost `owner` key,
+     dropping every address and pending change that key registered
+     */
+    pub fn recover_from(&mut self, owner: Pubkey) {
+        self.recovered_from = owner;
+        self.set_claim_delegate(None, false);
+        self.beneficiary = TimelockedAddress::default();
+        self.withdrawal_address = TimelockedAddress::default();
+    }
+
+    pub fn set_claim_delegate(&mut self, delegate: Option<Pubkey>, permissionless: bool) {
+        self.claim_delegate = delegate.unwrap_or_default();
+        self.permissionless_claim = permissionless;
+    }
+
+    /** Checks if `signer` may claim rewards of the subscription owned by `owner` */
+    pub fn is_claim_authority(&self, signer: Pubkey, owner: Pubkey) -> bool {
+        signer == owner
+            || self.permissionless_claim
+            || (self.claim_delegate != Pubkey::default() && signer == self.claim_delegate)
+    }
+
+    /** Wallet receiving claimed rewards at `time_now`, including a pending change past its delay */
+    pub fn reward_recipient(&self, owner: Pubkey, time_now: u64) -> Pubkey {
+        self.beneficiary.resolve(owner, time_now)
+    }
+
+    /** Wallet receiving withdrawn tokens at `time_now`, including a pending change past its delay */
+    pub fn withdrawal_destination(&self, owner: Pubkey, time_now: u64) -> Pubkey {
+        self.withdrawal_address.resolve(owner, time_now)
+    }
+
+    pub fn mature_slots(
+        &mut self,
+        time_now: u64,
+        host: &mut HostAccount,
+        campaigns: &[CampaignAccount],
+    ) -> Result<u64> {
         let mut matured_change: u64 = 0;
-        let mut matured_rewards: u64 = 0;
-        let mut released_change: u64 = 0;
-        // Cleanup matured slots and update balances
-        self.slots.retain(|slot: &Transaction| {
-            if slot.is_matured(time_now) {
-                match slot {
-                    Transaction::Deposit {
-                        amount,
-                        time_created,
-                        time_matured,
-                    } => {
-                        matured_change += amount;
-                        // Determine how many reward cycles have elapsed since maturity
-                        let epoch_length: u64 = time_matured - time_created;
-                        // Adds an additional cycle because the maturation has completed (1 cycle)
-                        let outstanding_cycles: u64 = (time_now - time_matured) / epoch_length + 1;
-                        matured_rewards += lamports_to_rewards(outstanding_cycles * amount);
-                    }
-                    Transaction::Withdraw { amount, .. } => {
-                        released_change += amount;
-                    }
+        let mut matured_rewards: u128 = 0;
+        let mut total_released: u64 = 0;
+        let time_rewarded: u64 = self.time_rewarded;
+        // Accrue rewards on the matured balance before it changes
+        let accrued: u64 = self.accrue(time_now, host, campaigns)?;
+        let model = host.reward_model()?;
+        // Collect balances of matured slots
+        for slot in self.slots.iter().filter(|slot| slot.is_matured(time_now)) {
+            match slot {
+                Transaction::Deposit {
+                    amount,
+                    time_created,
+                    time_matured,
+                } => {
+                    matured_change += amount;
+                    let rewards: u128 = model.matured_rewards(
+                        host,
+                        campaigns,
+                        *amount,
+                        *time_created,
+                        *time_matured,
+                        time_now,
+                    )?;
+                    // Blend the early adopter bonus over the lifetime of the slot
+                    matured_rewards += apply_bps(
+                        rewards,
+                        self.early_adopter_multiplier_bps(host, *time_created, time_now),
+                    )?;
+                }
+                // Re-lock releases left uncollected past their own collection window
+                Transaction::Withdraw {
+                    amount,
+                    time_released,
+                    ..
+                } if host.is_release_expired(*time_released, time_now) => {
+                    matured_change += amount;
+                }
+                Transaction::Withdraw { amount, .. } => {
+                    total_released += amount;
                 }
-
-                false
-            } else {
-                true
+            }
+        }
+        // Cleanup matured deposits and re-locked releases
+        self.slots.retain(|slot| match slot {
+            Transaction::Deposit { .. } => !slot.is_matured(time_now),
+            Transaction::Withdraw { time_released, .. } => {
+                !host.is_release_expired(*time_released, time_now)
             }
         });
         // Update total matured and released balances
         self.total_matured += matured_change;
-        self.total_released += released_change;
+        self.total_released = total_released;
+        host.total_locked += matured_change;
+        // Scale by the tier held while the slots matured
+        let matured_rewards: u128 =
+            apply_bps(matured_rewards, host.tier_multiplier_bps(self.tier))?;
         // Update the accrued rewards for claiming
-        self.total_rewards += matured_rewards;
+        let matured_rewards: u64 = carry_rewards(matured_rewards, &mut self.reward_remainder)?;
+        self.credit_accrued(matured_rewards, time_rewarded, time_now, host);
+        // Forfeit rewards left unclaimed past the expiry
+        self.expire_rewards(host, time_now);
         // Update member tier for new balances
-        self.tier = MemberTier::from_tier(MemberTier::get_tier(
-            self.total_amount - self.total_released,
-        ));
+        self.update_tier(host);
+        self.update_streak(time_now, host);
+
+        Ok(accrued + matured_rewards)
+    }
+
+    pub fn credit_rewards(&mut self, rewards: u64, time_now: u64) {
+        self.total_rewards += rewards;
+        self.reward_buckets.credit(rewards, time_now);
+    }
+
+    /** Forfeits rewards unclaimed for `reward_expiry_epochs`, returning the amount */
+    pub fn expire_rewards(&mut self, host: &HostAccount, time_now: u64) -> u64 {
+        if host.reward_expiry_epochs == 0 {
+            return 0;
+        }
+        let forfeited: u64 = self
+            .reward_buckets
+            .expire(host.reward_expiry_epochs, time_now);
+        self.total_rewards = self.total_rewards.saturating_sub(forfeited);
+        self.total_forfeited += forfeited;
+        forfeited
+    }
+
+    /**
+     Credits `rewards` accrued over `time_from` to `time_now` to
+     the epochs they accrued in, forfeiting the share accrued in
+     epochs already past the expiry.
+     */
+    fn credit_accrued(&mut self, rewards: u64, time_from: u64, time_now: u64, host: &HostAccount) {
+        let forfeited: u64 = self.reward_buckets.credit_range(
+            rewards,
+            time_from,
+            time_now,
+            host.reward_expiry_epochs,
+        );
+        self.total_rewards += rewards - forfeited;
+        self.total_forfeited += forfeited;
+    }
+
+    /** Event reporting rewards forfeited since the counter read `total_forfeited` */
+    pub fn forfeited_event(
+        &self,
+        total_forfeited: u64,
+        time_now: u64,
+    ) -> Option<RewardsForfeitedEvent> {
+        let amount: u64 = self.total_forfeited - total_forfeited;
+        (amount > 0).then(|| RewardsForfeitedEvent {
+            member_number: self.member_number,
+            amount,
+            epoch: RewardBuckets::epoch(time_now),
+        })
+    }
+
+    /** Accrues rewards on the matured balance for every second since `time_rewarded` */
+    fn accrue(
+        &mut self,
+        time_now: u64,
+        host: &mut HostAccount,
+        campaigns: &[CampaignAccount],
+    ) -> Result<u64> {
+        let model = host.reward_model()?;
+        // Bring global reward state up to date before checkpointing
+        model.update(host, time_now)?;
+        let rewards: u128 = model.accrued_rewards(host, campaigns, self, time_now)?;
+        // Scale by the tier held and loyalty earned since the last accrual
+        let rewards: u128 = apply_bps(rewards, host.tier_multiplier_bps(self.tier))?;
+        let rewards: u128 = apply_bps(
+            rewards,
+            self.average_loyalty_multiplier_bps(host, self.time_rewarded, time_now),
+        )?;
+        let rewards: u128 = apply_bps(
+            rewards,
+            self.early_adopter_multiplier_bps(host, self.time_rewarded, time_now),
+        )?;
+        let rewards: u64 = carry_rewards(rewards, &mut self.reward_remainder)?;
+        self.credit_accrued(rewards, self.time_rewarded, time_now, host);
+        self.time_rewarded = time_now;
+        self.reward_per_token_paid = host.reward_per_token;
+        Ok(rewards)
+    }
+
+    /** Tokens locked in matured and maturing deposits, excluding pending releases */
+    pub fn locked_amount(&self) -> u64 {
+        let maturing: u64 = self
+            .slots
+            .iter()
+            .map(|slot| match slot {
+                Transaction::Deposit { amount, .. } => *amount,
+                Transaction::Withdraw { .. } => 0,
+            })
+            .sum();
+        self.total_matured + maturing
+    }
+
+    /** Reward multiplier in basis points earned by the uninterrupted locking streak */
+    pub fn loyalty_multiplier_bps(&self, host: &HostAccount, time_now: u64) -> u64 {
+        if self.time_streak_start == 0 {
+            return BPS_DENOMINATOR;
+        }
+        let epochs: u64 = time_now.saturating_sub(self.time_streak_start) / MATURATION_PERIOD;
+        let bonus: u64 = epochs.saturating_mul(host.loyalty_bps_per_epoch);
+        BPS_DENOMINATOR + bonus.min(host.max_loyalty_bps)
+    }
+
+    /**
+     Loyalty multiplier in basis points averaged over
+     `time_from` to `time_to`, as the streak bonus steps up
+     with every epoch elapsed within the range.
+     */
+    pub fn average_loyalty_multiplier_bps(
+        &self,
+        host: &HostAccount,
+        time_from: u64,
+        time_to: u64,
+    ) -> u64 {
+        if self.time_streak_start == 0 || time_to <= time_from {
+            return self.loyalty_multiplier_bps(host, time_to);
+        }
+        let mut weighted: u128 = 0;
+        let mut time_segment: u64 = time_from.max(self.time_streak_start);
+        // Weight the bonus of each streak epoch by its overlap with the range
+        while time_segment < time_to {
+            let epochs: u64 = (time_segment - self.time_streak_start) / MATURATION_PERIOD;
+            let bonus: u64 = epochs
+                .saturating_mul(host.loyalty_bps_per_epoch)
+                .min(host.max_loyalty_bps);
+            let time_end: u64 = if bonus == host.max_loyalty_bps || host.loyalty_bps_per_epoch == 0
+            {
+                time_to
+            } else {
+                (self.time_streak_start + (epochs + 1) * MATURATION_PERIOD).min(time_to)
+            };
+            weighted += bonus as u128 * (time_end - time_segment) as u128;
+            time_segment = time_end;
+        }
+        BPS_DENOMINATOR + (weighted / (time_to - time_from) as u128) as u64
+    }
 
-        Ok(matured_rewards)
+    /**
+     Early adopter multiplier in basis points over `time_from`
+     to `time_to`, blended by the share of the range within the
+     bonus period following membership creation.
+     */
+    pub fn early_adopter_multiplier_bps(
+        &self,
+        host: &HostAccount,
+        time_from: u64,
+        time_to: u64,
+    ) -> u64 {
+        if !host.is_early_adopter(self.member_number) || time_to <= time_from {
+            return BPS_DENOMINATOR;
+        }
+        let time_end: u64 = self.time_created + host.early_adopter_period;
+        let overlap: u64 = time_to.min(time_end).saturating_sub(time_from);
+        let bonus: u64 = host
+            .early_adopter_multiplier_bps
+            .saturating_sub(BPS_DENOMINATOR);
+        BPS_DENOMINATOR + bonus * overlap / (time_to - time_from)
     }
 
-    fn get_unclaimed_rewards(&self, time_now: u64) -> u64 {
-        let unclaimed_rewards = self.total_rewards;
-        let total_epochs: u64 = self.get_unclaimed_epochs(time_now);
-        lamports_to_rewards(self.total_matured * total_epochs) + unclaimed_rewards
+    fn update_tier(&mut self, host: &HostAccount) {
+        self.tier = MemberTier::from_tier(MemberTier::get_tier(
+            self.total_amount - self.total_released,
+            host.mint_decimals,
+        ));
     }
 
-    fn get_unclaimed_epochs(&self, time_now: u64) -> u64 {
-        let time_elapsed: u64 = time_now - self.time_rewarded;
-        return time_elapsed / MATURATION_PERIOD;
+    /** Starts the streak when the locked balance reaches the first tier and resets it below */
+    fn update_streak(&mut self, time_now: u64, host: &HostAccount) {
+        let tier = MemberTier::get_tier(self.locked_amount(), host.mint_decimals);
+        if tier == MemberTier::Pending {
+            self.time_streak_start = 0;
+        } else if self.time_streak_start == 0 {
+            self.time_streak_start = time_now;
+        }
     }
 
     pub fn get_packed_len(&self) -> usize {
@@ -215,6 +719,354 @@ impl Default for SubscriptionAccount {
             time_created: 0,
             time_rewarded: 0,
             slots: Vec::new(),
+            sequence: 0,
+            member_number: 0,
+            referrer: Pubkey::default(),
+            total_referrals: 0,
+            referral_rewards: 0,
+            reward_buckets: RewardBuckets::default(),
+            vesting_amount: 0,
+            vesting_collected: 0,
+            vesting_carried: 0,
+            reward_remainder: 0,
+            reward_per_token_paid: 0,
+            time_vesting_start: 0,
+            time_vesting_end: 0,
+            time_streak_start: 0,
+            claim_delegate: Pubkey::default(),
+            permissionless_claim: false,
+            beneficiary: TimelockedAddress::default(),
+            withdrawal_address: TimelockedAddress::default(),
+            recovered_from: Pubkey::default(),
+            total_forfeited: 0,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vests_claimed_rewards_linearly() {
+        let mut subscription = SubscriptionAccount::default();
+        subscription.vest(1_000, 100, 0);
+        assert_eq!(subscription.collectable_amount(25), 250);
+        assert_eq!(subscription.collect_vested(25), 250);
+        assert_eq!(subscription.collectable_amount(25), 0);
+        // A new claim restarts the unvested balance and carries the vested part
+        subscription.vest(500, 100, 50);
+        assert_eq!(subscription.vesting_amount, 1_000);
+        assert_eq!(subscription.collectable_amount(50), 250);
+        assert_eq!(subscription.collect_vested(150), 1_250);
+        assert_eq!(subscription.collectable_amount(200), 0);
+    }
+
+    #[test]
+    fn averages_loyalty_over_streak_epochs() {
+        let host = HostAccount {
+            loyalty_bps_per_epoch: 1_000,
+            max_loyalty_bps: 1_500,
+            ..HostAccount::default()
+        };
+        let subscription = SubscriptionAccount {
+            time_streak_start: MATURATION_PERIOD,
+            ..SubscriptionAccount::default()
+        };
+        // Before the streak, then one epoch without and one epoch with the bonus
+        let multiplier: u64 =
+            subscription.average_loyalty_multiplier_bps(&host, 0, 3 * MATURATION_PERIOD);
+        assert_eq!(multiplier, BPS_DENOMINATOR + 1_000 / 3);
+        // The bonus stops increasing at the maximum
+        let multiplier: u64 = subscription.average_loyalty_multiplier_bps(
+            &host,
+            3 * MATURATION_PERIOD,
+            5 * MATURATION_PERIOD,
+        );
+        assert_eq!(multiplier, BPS_DENOMINATOR + 1_500);
+    }
+
+    #[test]
+    fn credits_active_referrers() {
+        let host = HostAccount {
+            referral_bps: 500,
+            ..HostAccount::default()
+        };
+        let mut referrer = SubscriptionAccount::default();
+        // Pending members can't refer
+        assert!(referrer.add_referral().is_err());
+        referrer.status = AccountStatus::Active.to_u8();
+        referrer.add_referral().unwrap();
+        assert_eq!(referrer.total_referrals, 1);
+        assert_eq!(referrer.credit_referral(1_000, &host, 0).unwrap(), 50);
+        assert_eq!(referrer.referral_rewards, 50);
+        assert_eq!(referrer.total_rewards, 50);
+    }
+
+    #[test]
+    fn reports_expired_rewards_without_emitting() {
+        let host = HostAccount {
+            reward_expiry_epochs: 1,
+            ..HostAccount::default()
+        };
+        let mut subscription = SubscriptionAccount::default();
+        subscription.credit_rewards(100, 0);
+        let total_forfeited: u64 = subscription.total_forfeited;
+        assert!(subscription
+            .forfeited_event(total_forfeited, MATURATION_PERIOD)
+            .is_none());
+        // Rewards credited two epochs ago are past the expiry
+        assert_eq!(
+            subscription.expire_rewards(&host, 2 * MATURATION_PERIOD),
+            100
+        );
+        let event = subscription
+            .forfeited_event(total_forfeited, 2 * MATURATION_PERIOD)
+            .unwrap();
+        assert_eq!(event.amount, 100);
+        assert_eq!(event.epoch, 2);
+        assert_eq!(subscription.total_rewards, 0);
+    }
+
+    #[test]
+    fn recovery_clears_registered_addresses() {
+        let (owner, attacker) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut subscription = SubscriptionAccount::default();
+        subscription.set_claim_delegate(Some(attacker), true);
+        subscription
+            .beneficiary
+            .request(Some(attacker), BENEFICIARY_DELAY, 0);
+        subscription.beneficiary.apply(BENEFICIARY_DELAY);
+        subscription
+            .beneficiary
+            .request(Some(attacker), BENEFICIARY_DELAY, BENEFICIARY_DELAY);
+        subscription
+            .withdrawal_address
+            .request(Some(attacker), WITHDRAWAL_ADDRESS_DELAY, 0);
+        subscription.recover_from(owner);
+        // Rewards and withdrawals return to the new owner
+        let new_owner = Pubkey::new_unique();
+        assert_eq!(subscription.recovered_from, owner);
+        assert!(!subscription.is_claim_authority(attacker, new_owner));
+        assert_eq!(
+            subscription.reward_recipient(new_owner, u64::MAX),
+            new_owner
+        );
+        assert_eq!(
+            subscription.withdrawal_destination(new_owner, u64::MAX),
+            new_owner
+        );
+        assert_eq!(
+            subscription.withdrawal_address,
+            TimelockedAddress::default()
+        );
+    }
+
+    #[test]
+    fn relocks_releases_left_uncollected() {
+        let mut host = HostAccount {
+            release_collection_window: 100,
+            ..HostAccount::default()
+        };
+        let mut subscription = SubscriptionAccount {
+            slots: vec![Transaction::Withdraw {
+                time_released: 10,
+                amount: 500,
+                delayed: false,
+            }],
+            total_amount: 500,
+            ..SubscriptionAccount::default()
+        };
+        // Collectable within the window
+        subscription.mature_slots(50, &mut host, &[]).unwrap();
+        assert_eq!(subscription.total_released, 500);
+        assert_eq!(subscription.slots.len(), 1);
+        // Locked again once the window passed
+        subscription.mature_slots(110, &mut host, &[]).unwrap();
+        assert_eq!(subscription.total_released, 0);
+        assert_eq!(subscription.total_matured, 500);
+        assert_eq!(host.total_locked, 500);
+        assert!(subscription.slots.is_empty());
+    }
+
+    #[test]
+    fn accrues_matured_balance_per_second() {
+        let mut host = HostAccount::default();
+        // Matured balance earning 1,000 entries per epoch
+        let mut subscription = SubscriptionAccount {
+            total_matured: 1_000 * REWARD_FACTOR * 10u64.pow(host.mint_decimals as u32),
+            ..SubscriptionAccount::default()
+        };
+        let half_epoch: u64 = MATURATION_PERIOD / 2;
+        let accrued: u64 = subscription
+            .mature_slots(half_epoch, &mut host, &[])
+            .unwrap();
+        assert_eq!(accrued, 500);
+        assert_eq!(subscription.time_rewarded, half_epoch);
+        // Partial epochs accrue without waiting for the epoch to complete
+        let time_now: u64 = half_epoch + 2_630;
+        let accrued: u64 = subscription.mature_slots(time_now, &mut host, &[]).unwrap();
+        assert_eq!(accrued, 1);
+        assert_eq!(subscription.total_rewards, 501);
+    }
+
+    #[test]
+    fn defers_claims_over_the_emission_cap() {
+        let mut host = HostAccount {
+            epoch_emission_cap: 100,
+            ..HostAccount::default()
+        };
+        let mut subscription = SubscriptionAccount::default();
+        subscription.credit_rewards(150, 0);
+        assert_eq!(subscription.claim(0, &mut host, &[]).unwrap(), 100);
+        assert_eq!(subscription.total_rewards, 50);
+        // Deferred rewards are claimable from the next epoch's budget
+        assert_eq!(subscription.claim(1, &mut host, &[]).unwrap(), 0);
+        let time_now: u64 = MATURATION_PERIOD;
+        assert_eq!(subscription.claim(time_now, &mut host, &[]).unwrap(), 50);
+        assert_eq!(subscription.total_rewards, 0);
+    }
+
+    #[test]
+    fn scales_accrual_by_tier_multiplier() {
+        let mut host = HostAccount::default();
+        host.tier_multipliers_bps[MemberTier::from_tier(MemberTier::Super) as usize] = 20_000;
+        // Super tier balance earning 1,000 entries per epoch at the base rate
+        let mut subscription = SubscriptionAccount {
+            tier: MemberTier::from_tier(MemberTier::Super),
+            total_matured: 1_000 * REWARD_FACTOR * 10u64.pow(host.mint_decimals as u32),
+            ..SubscriptionAccount::default()
+        };
+        let accrued: u64 = subscription
+            .mature_slots(MATURATION_PERIOD / 2, &mut host, &[])
+            .unwrap();
+        assert_eq!(accrued, 1_000);
+    }
+
+    #[test]
+    fn breaks_loyalty_streak_below_tier_minimum() {
+        let mut host = HostAccount {
+            loyalty_bps_per_epoch: 1_000,
+            max_loyalty_bps: 5_000,
+            ..HostAccount::default()
+        };
+        let minimum: u64 =
+            MemberTier::Virgin.required_tokens() * 10u64.pow(host.mint_decimals as u32);
+        host.total_locked = minimum;
+        let mut subscription = SubscriptionAccount {
+            total_matured: minimum,
+            ..SubscriptionAccount::default()
+        };
+        // The streak starts once the balance qualifies for a tier
+        subscription.mature_slots(10, &mut host, &[]).unwrap();
+        assert_eq!(subscription.time_streak_start, 10);
+        let time_now: u64 = 10 + 2 * MATURATION_PERIOD;
+        assert_eq!(
+            subscription.loyalty_multiplier_bps(&host, time_now),
+            BPS_DENOMINATOR + 2_000
+        );
+        // Releasing below the minimum forfeits the streak bonus
+        subscription.unlock(1, time_now, &mut host, &[]).unwrap();
+        assert_eq!(subscription.time_streak_start, 0);
+        assert_eq!(
+            subscription.loyalty_multiplier_bps(&host, time_now),
+            BPS_DENOMINATOR
+        );
+    }
+
+    #[test]
+    fn activates_pending_members_once() {
+        let host = HostAccount {
+            activation_reward: 25,
+            ..HostAccount::default()
+        };
+        let mut subscription = SubscriptionAccount::default();
+        assert_eq!(subscription.activate(&host, 0), Some(25));
+        assert_eq!(subscription.status, AccountStatus::Active.to_u8());
+        assert_eq!(subscription.total_rewards, 25);
+        // Later deposits don't grant the reward again
+        assert_eq!(subscription.activate(&host, 10), None);
+        assert_eq!(subscription.total_rewards, 25);
+    }
+
+    #[test]
+    fn blends_early_adopter_bonus_over_the_period() {
+        let host = HostAccount {
+            early_adopter_limit: 1,
+            early_adopter_multiplier_bps: 20_000,
+            early_adopter_period: 100,
+            ..HostAccount::default()
+        };
+        let mut subscription = SubscriptionAccount {
+            member_number: 1,
+            ..SubscriptionAccount::default()
+        };
+        assert_eq!(
+            subscription.early_adopter_multiplier_bps(&host, 0, 100),
+            20_000
+        );
+        // Half the range falls after the bonus period
+        assert_eq!(
+            subscription.early_adopter_multiplier_bps(&host, 50, 150),
+            15_000
+        );
+        assert_eq!(
+            subscription.early_adopter_multiplier_bps(&host, 100, 200),
+            BPS_DENOMINATOR
+        );
+        // Members past the limit earn the base rate
+        subscription.member_number = 2;
+        assert_eq!(
+            subscription.early_adopter_multiplier_bps(&host, 0, 100),
+            BPS_DENOMINATOR
+        );
+    }
+
+    #[test]
+    fn forfeits_expired_rewards_before_claiming() {
+        let mut host = HostAccount {
+            reward_expiry_epochs: 2,
+            ..HostAccount::default()
+        };
+        let mut subscription = SubscriptionAccount::default();
+        subscription.credit_rewards(100, 0);
+        subscription.credit_rewards(40, MATURATION_PERIOD);
+        // Only rewards of the epoch within the expiry remain claimable
+        let claimed: u64 = subscription
+            .claim(2 * MATURATION_PERIOD, &mut host, &[])
+            .unwrap();
+        assert_eq!(claimed, 40);
+        assert_eq!(subscription.total_forfeited, 100);
+        assert_eq!(subscription.total_rewards, 0);
+    }
+
+    #[test]
+    fn previews_without_mutating() {
+        let host = HostAccount {
+            epoch_emission_cap: 600,
+            ..HostAccount::default()
+        };
+        // Deposit earning 1,000 entries over its maturation epoch
+        let amount: u64 = 1_000 * REWARD_FACTOR * 10u64.pow(host.mint_decimals as u32);
+        let subscription = SubscriptionAccount {
+            total_amount: amount,
+            slots: vec![Transaction::Deposit {
+                amount,
+                time_created: 0,
+                time_matured: MATURATION_PERIOD,
+            }],
+            ..SubscriptionAccount::default()
+        };
+        let preview = subscription.preview(&host, &[], 10).unwrap();
+        assert_eq!(preview.total_matured, 0);
+        assert_eq!(preview.time_next_maturity, MATURATION_PERIOD);
+        // Projected past maturity, bounded by the epoch budget
+        let preview = subscription.preview(&host, &[], MATURATION_PERIOD).unwrap();
+        assert_eq!(preview.total_matured, amount);
+        assert_eq!(preview.pending_rewards, 1_000);
+        assert_eq!(preview.claimable_rewards, 600);
+        assert_eq!(preview.time_next_maturity, 0);
+        assert_eq!(subscription.slots.len(), 1);
+        assert_eq!(subscription.total_matured, 0);
+    }
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::utils::lamports_to_sol;
 
 #[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq)]
 pub enum MemberTier {
@@ -31,27 +32,43 @@ impl MemberTier {
         }
     }
 
+    /** Whole tokens locked to qualify for the tier */
     pub fn required_tokens(&self) -> u64 {
         match self {
-            MemberTier::Pending => 1,
-            MemberTier::Virgin => 3_000_000_000_000, // 3000 $7.50 USD @ 1.5MCAP
-            MemberTier::Super => 100_000_000_000_000, // 100K $250 USD
-            MemberTier::Mega => 2_000_000_000_000_000, // 2M $5000 USD
-            MemberTier::Giga => 5_000_000_000_000_000, // 5M $12,000 USD
+            MemberTier::Pending => 0,
+            MemberTier::Virgin => 3_000,   // 3000 $7.50 USD @ 1.5MCAP
+            MemberTier::Super => 100_000,  // 100K $250 USD
+            MemberTier::Mega => 2_000_000, // 2M $5000 USD
+            MemberTier::Giga => 5_000_000, // 5M $12,000 USD
         }
     }
 
-    pub fn get_tier(lamports: u64) -> MemberTier {
-        if lamports >= MemberTier::Giga.required_tokens() {
+    pub fn get_tier(lamports: u64, decimals: u8) -> MemberTier {
+        let tokens: u64 = lamports_to_sol(lamports, decimals);
+        if tokens >= MemberTier::Giga.required_tokens() {
             MemberTier::Giga
-        } else if lamports >= MemberTier::Mega.required_tokens() {
+        } else if tokens >= MemberTier::Mega.required_tokens() {
             MemberTier::Mega
-        } else if lamports >= MemberTier::Super.required_tokens() {
+        } else if tokens >= MemberTier::Super.required_tokens() {
             MemberTier::Super
-        } else if lamports >= MemberTier::Virgin.required_tokens() {
+        } else if tokens >= MemberTier::Virgin.required_tokens() {
             MemberTier::Virgin
         } else {
             MemberTier::Pending
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn qualifies_tiers_by_mint_decimals() {
+        let lamports: u64 = 100_000 * 10u64.pow(6);
+        assert!(MemberTier::get_tier(lamports, 6) == MemberTier::Super);
+        // The same raw amount is a thousandth of the tokens at 9 decimals
+        assert!(MemberTier::get_tier(lamports, 9) == MemberTier::Pending);
+        assert!(MemberTier::get_tier(lamports * 30, 9) == MemberTier::Virgin);
+    }
+}
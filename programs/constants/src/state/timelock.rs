@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+/**
+ Address registered by the owner which only changes after a
+ delay, so a compromised key can't immediately redirect the
+ funds it receives. The default key resolves to the owner.
+ */
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct TimelockedAddress {
+    /** Address in effect, the default key resolves to the owner */
+    pub address: Pubkey,
+    /** Address replacing `address` once the change delay has elapsed */
+    pub pending: Pubkey,
+    /** Date the pending address takes effect, zero when no change is pending */
+    pub time_effective: u64,
+}
+
+impl TimelockedAddress {
+    /** Schedules `address` after `delay`, returning the date it takes effect */
+    pub fn request(&mut self, address: Option<Pubkey>, delay: u64, time_now: u64) -> u64 {
+        // Replaces any change already pending and restarts the delay
+        self.pending = address.unwrap_or_default();
+        self.time_effective = time_now + delay;
+        self.time_effective
+    }
+
+    /** Promotes the pending address once the change delay has elapsed */
+    pub fn apply(&mut self, time_now: u64) -> bool {
+        if !self.is_due(time_now) {
+            return false;
+        }
+        self.address = self.pending;
+        self.pending = Pubkey::default();
+        self.time_effective = 0;
+        true
+    }
+
+    /** Address in effect at `time_now`, including a pending change past its delay */
+    pub fn resolve(&self, owner: Pubkey, time_now: u64) -> Pubkey {
+        let address: Pubkey = if self.is_due(time_now) {
+            self.pending
+        } else {
+            self.address
+        };
+        if address == Pubkey::default() {
+            owner
+        } else {
+            address
+        }
+    }
+
+    fn is_due(&self, time_now: u64) -> bool {
+        self.time_effective != 0 && time_now >= self.time_effective
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn applies_changes_after_the_delay() {
+        let (owner, address) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut timelock = TimelockedAddress::default();
+        assert_eq!(timelock.request(Some(address), 100, 10), 110);
+        assert!(!timelock.apply(109));
+        assert_eq!(timelock.resolve(owner, 109), owner);
+        // The pending change resolves as soon as it is due
+        assert_eq!(timelock.resolve(owner, 110), address);
+        assert!(timelock.apply(110));
+        assert_eq!(timelock.address, address);
+        assert_eq!(timelock.time_effective, 0);
+        // Clearing the address resolves to the owner again
+        timelock.request(None, 100, 200);
+        assert!(timelock.apply(300));
+        assert_eq!(timelock.resolve(owner, 300), owner);
+    }
+}
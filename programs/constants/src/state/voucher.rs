@@ -0,0 +1,166 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    ed25519_program,
+    sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+};
+use crate::{constants::*, errors::VoucherError};
+
+/** Size of the Ed25519 instruction header and its signature offsets */
+const ED25519_OFFSETS_END: usize = 16;
+
+/**
+ Records a redeemed voucher nonce per member. The account is
+ created when the voucher is redeemed, so a replayed nonce
+ fails to initialize its address. There is deliberately no
+ close path, as the account is the permanent replay record.
+ */
+#[account]
+pub struct VoucherAccount {
+    /** Schema version from v0 up to v255. Defaults to the `LATEST_VERSION` constant. */
+    pub version: u8,
+    /** Nonce of the redeemed voucher */
+    pub nonce: u64,
+    /** Member wallet redeeming the voucher */
+    pub member: Pubkey,
+    /** Date the voucher was redeemed */
+    pub time_redeemed: u64,
+}
+
+impl VoucherAccount {
+    pub const LATEST_VERSION: u8 = 1;
+
+    pub const fn space() -> usize {
+        ANCHOR_DISCRIMINATOR_SIZE + std::mem::size_of::<VoucherAccount>()
+    }
+
+    /** Domain separator prefixing every signed voucher message */
+    pub const DOMAIN: &[u8] = b"lfv-voucher";
+
+    /**
+     Message signed by the voucher signer over the domain,
+     program, host, member, amount, nonce and expiry.
+     */
+    pub fn message(
+        host: Pubkey,
+        member: Pubkey,
+        amount: u64,
+        nonce: u64,
+        time_expiry: u64,
+    ) -> Vec<u8> {
+        [
+            Self::DOMAIN,
+            crate::ID.as_ref(),
+            host.as_ref(),
+            member.as_ref(),
+            &amount.to_le_bytes(),
+            &nonce.to_le_bytes(),
+            &time_expiry.to_le_bytes(),
+        ]
+        .concat()
+    }
+
+    /**
+     Checks the instruction preceding the current one verifies
+     a single Ed25519 signature by `signer` over `message`, with
+     the signature, key and message inlined in its own data.
+     */
+    pub fn verify_signature(
+        instructions: &AccountInfo,
+        signer: Pubkey,
+        message: &[u8],
+    ) -> Result<()> {
+        let index: u16 = load_current_index_checked(instructions)?;
+        require!(index > 0, VoucherError::InvalidSignature);
+        let instruction = load_instruction_at_checked(index as usize - 1, instructions)?;
+        require!(
+            instruction.program_id == ed25519_program::ID && instruction.accounts.is_empty(),
+            VoucherError::InvalidSignature
+        );
+
+        let (public_key, signed_message) = parse_ed25519(&instruction.data)?;
+        require!(
+            public_key == signer.as_ref() && signed_message == message,
+            VoucherError::InvalidSignature
+        );
+        Ok(())
+    }
+}
+
+/**
+ Extracts the public key and message of an Ed25519 program
+ instruction verifying a single signature, requiring every
+ offset to reference data inlined in the instruction itself.
+ */
+pub fn parse_ed25519(data: &[u8]) -> Result<(&[u8], &[u8])> {
+    require!(
+        data.len() >= ED25519_OFFSETS_END && data[0] == 1,
+        VoucherError::InvalidSignature
+    );
+    let read_u16 = |offset: usize| u16::from_le_bytes([data[offset], data[offset + 1]]);
+    // Offsets must reference data inlined in the Ed25519 instruction itself
+    require!(
+        read_u16(4) == u16::MAX && read_u16(8) == u16::MAX && read_u16(14) == u16::MAX,
+        VoucherError::InvalidSignature
+    );
+    let public_key_offset: usize = read_u16(6) as usize;
+    let message_offset: usize = read_u16(10) as usize;
+    let message_size: usize = read_u16(12) as usize;
+
+    let public_key = data
+        .get(public_key_offset..public_key_offset + 32)
+        .ok_or(VoucherError::InvalidSignature)?;
+    let message = data
+        .get(message_offset..message_offset + message_size)
+        .ok_or(VoucherError::InvalidSignature)?;
+    Ok((public_key, message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /** Ed25519 instruction data with the key, signature and message inlined */
+    fn instruction_data(public_key: &[u8; 32], message: &[u8]) -> Vec<u8> {
+        let offsets: [u16; 7] = [
+            48,
+            u16::MAX,
+            16,
+            u16::MAX,
+            112,
+            message.len() as u16,
+            u16::MAX,
+        ];
+        let mut data: Vec<u8> = vec![1, 0];
+        offsets
+            .iter()
+            .for_each(|offset| data.extend_from_slice(&offset.to_le_bytes()));
+        data.extend_from_slice(public_key);
+        data.extend_from_slice(&[0; 64]);
+        data.extend_from_slice(message);
+        data
+    }
+
+    #[test]
+    fn parses_inlined_ed25519_offsets() {
+        let signer = Pubkey::new_unique();
+        let message = VoucherAccount::message(Pubkey::new_unique(), signer, 100, 7, 30);
+        let data = instruction_data(&signer.to_bytes(), &message);
+        let (public_key, signed_message) = parse_ed25519(&data).unwrap();
+        assert_eq!(public_key, signer.as_ref());
+        assert_eq!(signed_message, message.as_slice());
+        assert!(message.starts_with(VoucherAccount::DOMAIN));
+
+        // Offsets referencing another instruction are rejected
+        let mut foreign = data.clone();
+        foreign[8..10].copy_from_slice(&0u16.to_le_bytes());
+        assert!(parse_ed25519(&foreign).is_err());
+        // Messages running past the instruction data are rejected
+        let mut truncated = data.clone();
+        truncated.truncate(data.len() - 1);
+        assert!(parse_ed25519(&truncated).is_err());
+        // Only a single signature is supported
+        let mut multiple = data;
+        multiple[0] = 2;
+        assert!(parse_ed25519(&multiple).is_err());
+    }
+}
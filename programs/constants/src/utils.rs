@@ -1,22 +1,30 @@
-use crate::constants::*;
+use anchor_lang::prelude::*;
+use crate::errors::MathError;
 
-pub const fn lamports_to_sol(lamports: u64) -> u64 {
-    match lamports.checked_div(10u64.pow(9)) {
+pub const fn lamports_to_sol(lamports: u64, decimals: u8) -> u64 {
+    match lamports.checked_div(10u64.pow(decimals as u32)) {
         Some(result) => result,
         None => panic!("Multiplication overflowed"),
     }
 }
 
-pub const fn lamports_to_rewards(lamports: u64) -> u64 {
-    match lamports_to_sol(lamports).checked_div(REWARD_FACTOR) {
-        Some(result) => result,
-        None => panic!("Multiplication overflowed"),
-    }
+/** Converts whole entry tokens to base units of a mint with `decimals` */
+pub fn rewards_to_lamports(rewards: u64, decimals: u8) -> Result<u64> {
+    10u64
+        .checked_pow(decimals as u32)
+        .and_then(|factor| rewards.checked_mul(factor))
+        .ok_or(error!(MathError::Overflow))
 }
 
-pub const fn rewards_to_lamports(lamports: u64) -> u64 {
-    match lamports.checked_mul(10u64.pow(4)) {
-        Some(result) => result,
-        None => panic!("Multiplication overflowed"),
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_rewards_to_lamports() {
+        assert_eq!(rewards_to_lamports(25, 4).unwrap(), 250_000);
+        // Large distribution amounts fail instead of panicking
+        assert!(rewards_to_lamports(u64::MAX / 10, 4).is_err());
+        assert!(rewards_to_lamports(1, 20).is_err());
     }
 }
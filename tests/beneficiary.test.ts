@@ -0,0 +1,88 @@
+import * as anchor from "@coral-xyz/anchor";
+import BN from "bn.js";
+import assert from "assert";
+import * as web3 from "@solana/web3.js";
+import spl from "@solana/spl-token";
+import { program } from "../client/constants";
+import { findRewardTokenMintAddress } from "@/pda";
+import {
+  assertErrorAsync,
+  claimAccounts,
+  confirm,
+  createMember,
+} from "@/testing/utils";
+const DECIMALS = 9;
+const AMOUNT = 100;
+const BENEFICIARY_DELAY = 259_200;
+
+describe("Claim to beneficiary", async () => {
+  // Configure the client to use the local cluster
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const memberWallet = new web3.Keypair(),
+    beneficiaryWallet = new web3.Keypair();
+
+  const amount = new BN(AMOUNT * Math.pow(10, DECIMALS));
+
+  let subscription: web3.PublicKey;
+
+  beforeAll(async () => {
+    subscription = await createMember(amount, memberWallet);
+  });
+
+  it("schedules the beneficiary behind the change delay", async () => {
+    await program.methods
+      .setBeneficiary(beneficiaryWallet.publicKey, null)
+      .accounts({ subscription, signer: memberWallet.publicKey })
+      .signers([memberWallet])
+      .rpc()
+      .then(confirm);
+
+    const account =
+      await program.account.subscriptionAccount.fetch(subscription);
+    assert(account.beneficiary.pending.equals(beneficiaryWallet.publicKey));
+    assert(account.beneficiary.address.equals(web3.PublicKey.default));
+    assert(
+      account.beneficiary.timeEffective.gte(
+        new BN(Math.floor(Date.now() / 1000) + BENEFICIARY_DELAY - 60)
+      )
+    );
+  });
+
+  it("fails to claim to the beneficiary before the delay", async () => {
+    await assertErrorAsync(
+      program.methods
+        .claim(null)
+        .accounts(
+          claimAccounts(
+            memberWallet,
+            memberWallet.publicKey,
+            beneficiaryWallet.publicKey
+          )
+        )
+        .signers([memberWallet])
+        .rpc(),
+      "An address constraint was violated"
+    );
+  });
+
+  it("creates the owner's reward token account on claim", async () => {
+    await program.methods
+      .claim(null)
+      .accounts(claimAccounts(memberWallet, memberWallet.publicKey))
+      .signers([memberWallet])
+      .rpc()
+      .then(confirm);
+
+    const tokenAccount = await spl.getAccount(
+      program.provider.connection,
+      spl.getAssociatedTokenAddressSync(
+        findRewardTokenMintAddress(),
+        memberWallet.publicKey
+      )
+    );
+    assert(tokenAccount.owner.equals(memberWallet.publicKey));
+  });
+
+  it.skip("mints to the beneficiary once the change delay has elapsed");
+});
@@ -0,0 +1,119 @@
+import * as anchor from "@coral-xyz/anchor";
+import BN from "bn.js";
+import assert from "assert";
+import * as web3 from "@solana/web3.js";
+import { program } from "../client/constants";
+import { findCampaignAccountAddress, findHostAccountAddress } from "@/pda";
+import {
+  adminWallet,
+  assertErrorAsync,
+  confirm,
+  createMember,
+  sleep,
+} from "@/testing/utils";
+const DECIMALS = 9;
+const AMOUNT = 100;
+const BPS_DENOMINATOR = 10_000;
+const MAX_MULTIPLIER_BPS = 100_000;
+const DURATION = 10;
+
+describe("Bonus campaigns", async () => {
+  // Configure the client to use the local cluster
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const memberWallet = new web3.Keypair();
+
+  const amount = new BN(AMOUNT * Math.pow(10, DECIMALS));
+
+  const host = findHostAccountAddress();
+
+  let subscription: web3.PublicKey, campaign: web3.PublicKey, timeStart: BN;
+
+  const createCampaign = (multiplierBps: number) =>
+    program.methods
+      .createCampaign({
+        timeStart,
+        timeEnd: timeStart.add(new BN(DURATION)),
+        multiplierBps: new BN(multiplierBps),
+      })
+      .accounts({
+        host,
+        campaign,
+        admin: adminWallet.publicKey,
+        systemProgram: web3.SystemProgram.programId,
+      })
+      .signers([adminWallet])
+      .rpc();
+
+  const closeCampaign = () =>
+    program.methods
+      .closeCampaign()
+      .accounts({ host, campaign, admin: adminWallet.publicKey })
+      .signers([adminWallet])
+      .rpc();
+
+  beforeAll(async () => {
+    subscription = await createMember(amount, memberWallet);
+    const { totalCampaigns } = await program.account.hostAccount.fetch(host);
+    campaign = findCampaignAccountAddress(totalCampaigns);
+    timeStart = new BN(Math.floor(Date.now() / 1000) + 5);
+  });
+
+  it("fails for multipliers that don't boost rewards", async () => {
+    await assertErrorAsync(
+      createCampaign(BPS_DENOMINATOR),
+      "Invalid campaign"
+    );
+  });
+
+  it("fails for multipliers above the maximum", async () => {
+    await assertErrorAsync(
+      createCampaign(MAX_MULTIPLIER_BPS + 1),
+      "Reward multiplier exceeds the maximum"
+    );
+  });
+
+  it("creates an open campaign", async () => {
+    const { openCampaigns } = await program.account.hostAccount.fetch(host);
+    await createCampaign(2 * BPS_DENOMINATOR).then(confirm);
+
+    const account = await program.account.hostAccount.fetch(host);
+    assert(account.openCampaigns.eq(openCampaigns.add(new BN(1))));
+
+    const created = await program.account.campaignAccount.fetch(campaign);
+    assert(created.id.eq(account.totalCampaigns.sub(new BN(1))));
+    assert(created.timeStart.eq(timeStart));
+    assert(created.multiplierBps.eq(new BN(2 * BPS_DENOMINATOR)));
+  });
+
+  it("fails to close a running campaign", async () => {
+    await assertErrorAsync(
+      closeCampaign(),
+      "Campaign settlement period has not elapsed"
+    );
+  });
+
+  it("fails to close an ended campaign before members settled", async () => {
+    await sleep((DURATION + 10) * 1000);
+    await assertErrorAsync(
+      closeCampaign(),
+      "Campaign settlement period has not elapsed"
+    );
+    const account = await program.account.campaignAccount.fetch(campaign);
+    assert(account.timeEnd.eq(timeStart.add(new BN(DURATION))));
+  }, 60_000);
+
+  it("fails to preview without every open campaign", async () => {
+    await assertErrorAsync(
+      program.methods
+        .previewRewards(null)
+        .accounts({ subscription, host })
+        .view(),
+      "Every open campaign must be passed"
+    );
+  });
+
+  it.skip("boosts rewards accrued within the campaign window");
+
+  it.skip("closes the campaign once the settlement period elapsed");
+});
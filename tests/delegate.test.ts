@@ -0,0 +1,76 @@
+import * as anchor from "@coral-xyz/anchor";
+import BN from "bn.js";
+import assert from "assert";
+import * as web3 from "@solana/web3.js";
+import { program } from "../client/constants";
+import {
+  adminWallet,
+  assertErrorAsync,
+  claimAccounts,
+  confirm,
+  createMember,
+} from "@/testing/utils";
+const DECIMALS = 9;
+const AMOUNT = 100;
+
+describe("Delegated claim authority", async () => {
+  // Configure the client to use the local cluster
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const memberWallet = new web3.Keypair();
+
+  const amount = new BN(AMOUNT * Math.pow(10, DECIMALS));
+
+  let subscription: web3.PublicKey;
+
+  // Funded keeper paying for the reward token account
+  const keeperClaim = () =>
+    program.methods
+      .claim(null)
+      .accounts(claimAccounts(memberWallet, adminWallet.publicKey))
+      .signers([adminWallet])
+      .rpc();
+
+  const setClaimDelegate = (
+    delegate: web3.PublicKey | null,
+    permissionless: boolean
+  ) =>
+    program.methods
+      .setClaimDelegate(delegate, permissionless, null)
+      .accounts({ subscription, signer: memberWallet.publicKey })
+      .signers([memberWallet])
+      .rpc();
+
+  beforeAll(async () => {
+    subscription = await createMember(amount, memberWallet);
+  });
+
+  it("fails to claim without the member's approval", async () => {
+    await assertErrorAsync(
+      keeperClaim(),
+      "Signer is not authorized to claim for the member"
+    );
+  });
+
+  it("claims as the approved delegate", async () => {
+    await setClaimDelegate(adminWallet.publicKey, false).then(confirm);
+    await keeperClaim().then(confirm);
+
+    const account =
+      await program.account.subscriptionAccount.fetch(subscription);
+    assert(account.claimDelegate.equals(adminWallet.publicKey));
+  });
+
+  it("claims by anyone when permissionless", async () => {
+    await setClaimDelegate(null, true).then(confirm);
+    await keeperClaim().then(confirm);
+  });
+
+  it("fails to claim once the approval is revoked", async () => {
+    await setClaimDelegate(null, false).then(confirm);
+    await assertErrorAsync(
+      keeperClaim(),
+      "Signer is not authorized to claim for the member"
+    );
+  });
+});
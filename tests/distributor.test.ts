@@ -0,0 +1,125 @@
+import * as anchor from "@coral-xyz/anchor";
+import BN from "bn.js";
+import assert from "assert";
+import * as web3 from "@solana/web3.js";
+import spl from "@solana/spl-token";
+import { program } from "../client/constants";
+import {
+  findDistributorAccountAddress,
+  findHostAccountAddress,
+  findRewardTokenMintAddress,
+} from "@/pda";
+import {
+  adminWallet,
+  assertErrorAsync,
+  confirm,
+  createMember,
+} from "@/testing/utils";
+const DECIMALS = 9;
+const AMOUNT = 100;
+const TOTAL_RECIPIENTS = 4;
+
+describe("Merkle distributor", async () => {
+  // Configure the client to use the local cluster
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const memberWallet = new web3.Keypair();
+
+  const amount = new BN(AMOUNT * Math.pow(10, DECIMALS));
+
+  const host = findHostAccountAddress();
+
+  const root = Array(32).fill(1);
+
+  let distributor: web3.PublicKey;
+
+  const createDistribution = (
+    totalRecipients: number,
+    admin: web3.Keypair = adminWallet
+  ) =>
+    program.methods
+      .createDistribution({
+        root,
+        totalAmount: new BN(AMOUNT),
+        totalRecipients,
+      })
+      .accounts({
+        host,
+        distributor,
+        admin: admin.publicKey,
+        systemProgram: web3.SystemProgram.programId,
+      })
+      .signers([admin])
+      .rpc();
+
+  const claimDistribution = (index: number, proof: number[][]) => {
+    const mint = findRewardTokenMintAddress();
+    return program.methods
+      .claimDistribution(index, new BN(AMOUNT), proof)
+      .accounts({
+        distributor,
+        mint,
+        destinationTokenAccount: spl.getAssociatedTokenAddressSync(
+          mint,
+          memberWallet.publicKey
+        ),
+        signer: memberWallet.publicKey,
+        tokenProgram: spl.TOKEN_PROGRAM_ID,
+        associatedTokenProgram: spl.ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: web3.SystemProgram.programId,
+      })
+      .signers([memberWallet])
+      .rpc();
+  };
+
+  beforeAll(async () => {
+    await createMember(amount, memberWallet);
+    const { totalDistributions } =
+      await program.account.hostAccount.fetch(host);
+    distributor = findDistributorAccountAddress(totalDistributions);
+  });
+
+  describe("Create", () => {
+    it("fails when not signed by the admin", async () => {
+      await assertErrorAsync(
+        createDistribution(TOTAL_RECIPIENTS, memberWallet),
+        "An address constraint was violated"
+      );
+    });
+
+    it("fails without recipients", async () => {
+      await assertErrorAsync(
+        createDistribution(0),
+        "Invalid number of distribution recipients"
+      );
+    });
+
+    it("publishes the allocation root", async () => {
+      await createDistribution(TOTAL_RECIPIENTS).then(confirm);
+
+      const account = await program.account.distributorAccount.fetch(
+        distributor
+      );
+      assert(Buffer.from(account.root).equals(Buffer.from(root)));
+      assert(account.totalRecipients === TOTAL_RECIPIENTS);
+    });
+  });
+
+  describe("Claim", () => {
+    it("fails for an index beyond the recipients", async () => {
+      await assertErrorAsync(
+        claimDistribution(TOTAL_RECIPIENTS, []),
+        "Recipient index out of bounds"
+      );
+    });
+
+    it("fails for an invalid proof", async () => {
+      await assertErrorAsync(
+        claimDistribution(0, [Array(32).fill(2)]),
+        "Invalid Merkle proof"
+      );
+    });
+
+    it.skip("mints the allocation once per recipient index");
+  });
+});
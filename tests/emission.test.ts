@@ -0,0 +1,86 @@
+import * as anchor from "@coral-xyz/anchor";
+import BN from "bn.js";
+import assert from "assert";
+import * as web3 from "@solana/web3.js";
+import spl from "@solana/spl-token";
+import { program } from "../client/constants";
+import { findHostAccountAddress } from "@/pda";
+import {
+  adminWallet,
+  claimAccounts,
+  confirm,
+  createMember,
+  hostParams,
+} from "@/testing/utils";
+const DECIMALS = 9;
+const AMOUNT = 100;
+const ACTIVATION_REWARD = 3;
+const EPOCH_EMISSION_CAP = 1;
+
+describe("Epoch emission cap", async () => {
+  // Configure the client to use the local cluster
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const memberWallet = new web3.Keypair();
+
+  const amount = new BN(AMOUNT * Math.pow(10, DECIMALS));
+
+  const host = findHostAccountAddress();
+
+  const accounts = claimAccounts(memberWallet, memberWallet.publicKey);
+
+  const configure = (params: object) =>
+    program.methods
+      .configureHost(hostParams(params))
+      .accounts({ host, admin: adminWallet.publicKey })
+      .signers([adminWallet])
+      .rpc();
+
+  const remainingEmission = (): Promise<BN> =>
+    program.methods.remainingEmission().accounts({ host }).view();
+
+  beforeAll(async () => {
+    await configure({
+      activationReward: new BN(ACTIVATION_REWARD),
+      epochEmissionCap: new BN(EPOCH_EMISSION_CAP),
+    }).then(confirm);
+    await createMember(amount, memberWallet);
+  });
+
+  it("defers rewards over the epoch budget", async () => {
+    const before = await program.account.subscriptionAccount.fetch(
+      accounts.subscription
+    );
+    const remaining = await remainingEmission();
+    assert(before.totalRewards.gt(remaining));
+
+    await program.methods
+      .claim(null)
+      .accounts(accounts)
+      .signers([memberWallet])
+      .rpc()
+      .then(confirm);
+
+    // Only the remaining budget is minted, the rest stays claimable
+    const after = await program.account.subscriptionAccount.fetch(
+      accounts.subscription
+    );
+    assert(after.totalRewards.eq(before.totalRewards.sub(remaining)));
+    assert((await remainingEmission()).eq(new BN(0)));
+
+    const mint = await spl.getMint(program.provider.connection, accounts.mint);
+    const destination = await spl.getAccount(
+      program.provider.connection,
+      accounts.destinationTokenAccount
+    );
+    assert(
+      new BN(destination.amount.toString()).eq(
+        remaining.mul(new BN(10).pow(new BN(mint.decimals)))
+      )
+    );
+  });
+
+  afterAll(async () => {
+    await configure({}).then(confirm);
+  });
+});
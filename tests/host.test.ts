@@ -0,0 +1,101 @@
+import * as anchor from "@coral-xyz/anchor";
+import BN from "bn.js";
+import assert from "assert";
+import * as web3 from "@solana/web3.js";
+import { program } from "../client/constants";
+import { findHostAccountAddress } from "@/pda";
+import {
+  adminWallet,
+  assertErrorAsync,
+  confirm,
+  hostParams,
+} from "@/testing/utils";
+const BPS_DENOMINATOR = 10_000;
+const MAX_MULTIPLIER_BPS = 100_000;
+
+describe("Host configuration", async () => {
+  // Configure the client to use the local cluster
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const host = findHostAccountAddress();
+
+  const configure = (params: object) =>
+    program.methods
+      .configureHost(hostParams(params))
+      .accounts({ host, admin: adminWallet.publicKey })
+      .signers([adminWallet])
+      .rpc();
+
+  it("fails when not signed by the admin", async () => {
+    const wallet = new web3.Keypair();
+    await assertErrorAsync(
+      program.methods
+        .configureHost(hostParams())
+        .accounts({ host, admin: wallet.publicKey })
+        .signers([wallet])
+        .rpc(),
+      "An address constraint was violated"
+    );
+  });
+
+  it("fails for an unknown reward model", async () => {
+    await assertErrorAsync(
+      configure({ rewardModel: 9 }),
+      "Invalid reward model"
+    );
+  });
+
+  it("fails for multipliers above the maximum", async () => {
+    await assertErrorAsync(
+      configure({ earlyAdopterMultiplierBps: new BN(MAX_MULTIPLIER_BPS + 1) }),
+      "Reward multiplier exceeds the maximum"
+    );
+    await assertErrorAsync(
+      configure({ maxLoyaltyBps: new BN(MAX_MULTIPLIER_BPS) }),
+      "Reward multiplier exceeds the maximum"
+    );
+  });
+
+  it("fails for a reward expiry beyond the tracked epochs", async () => {
+    await assertErrorAsync(
+      configure({ rewardExpiryEpochs: new BN(255) }),
+      "Reward expiry exceeds the tracked epochs"
+    );
+  });
+
+  it("applies a reward expiry within the tracked epochs", async () => {
+    await configure({ rewardExpiryEpochs: new BN(3) }).then(confirm);
+
+    const account = await program.account.hostAccount.fetch(host);
+    assert(account.rewardExpiryEpochs.eq(new BN(3)));
+  });
+
+  it("fails for a referral share above the claimed rewards", async () => {
+    await assertErrorAsync(
+      configure({ referralBps: new BN(BPS_DENOMINATOR + 1) }),
+      "Referral share exceeds the claimed rewards"
+    );
+  });
+
+  it("applies the pro-rata model and epoch emission cap", async () => {
+    await configure({
+      rewardModel: 1,
+      emissionRate: new BN(1_000),
+      epochEmissionCap: new BN(5_000),
+    }).then(confirm);
+
+    const account = await program.account.hostAccount.fetch(host);
+    assert(account.rewardModel === 1);
+    assert(account.epochEmissionCap.eq(new BN(5_000)));
+
+    const remaining: BN = await program.methods
+      .remainingEmission()
+      .accounts({ host })
+      .view();
+    assert(remaining.lte(new BN(5_000)));
+  });
+
+  afterAll(async () => {
+    await configure({}).then(confirm);
+  });
+});
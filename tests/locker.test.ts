@@ -4,7 +4,11 @@ import assert from "assert";
 import * as web3 from "@solana/web3.js";
 import spl from "@solana/spl-token";
 import { program } from "../client/constants";
-import { findSubscriptionAccountAddress, findVaultAccountAddress } from "@/pda";
+import {
+  findHostAccountAddress,
+  findSubscriptionAccountAddress,
+  findVaultAccountAddress,
+} from "@/pda";
 const DECIMALS = 9;
 const AMOUNT = 100;
 
@@ -131,11 +135,10 @@ describe("Rewards Program", async () => {
       const txHash = await program.methods
         .initialize()
         .accounts({
-          memberAccount,
-          vaultTokenAccount,
-          mint: tokenMint.publicKey,
+          subscription: memberAccount,
+          host: findHostAccountAddress(),
+          referrer: null,
           signer: memberWallet.publicKey,
-          tokenProgram: spl.TOKEN_PROGRAM_ID,
           systemProgram: web3.SystemProgram.programId,
         })
         .signers([memberWallet])
@@ -255,9 +258,10 @@ describe("Rewards Program", async () => {
       //   await program.account.memberAccount.fetch(memberPool);
       // Send transaction
       const txHash = await program.methods
-        .deposit(amount)
+        .deposit(amount, null)
         .accounts({
-          memberAccount,
+          subscription: memberAccount,
+          host: findHostAccountAddress(),
           vaultTokenAccount,
           sourceTokenAccount,
           mint: tokenMint.publicKey,
@@ -356,11 +360,12 @@ describe("Rewards Program", async () => {
       //   await program.account.memberAccount.fetch(memberPool);
       // Send transaction
       const txHash = await program.methods
-        .withdraw(amount)
+        .withdraw(null)
         .accounts({
-          memberAccount,
+          subscription: memberAccount,
+          host: findHostAccountAddress(),
           vaultTokenAccount,
-          sourceTokenAccount,
+          destinationTokenAccount: sourceTokenAccount,
           mint: tokenMint.publicKey,
           signer: memberWallet.publicKey,
           systemProgram: web3.SystemProgram.programId,
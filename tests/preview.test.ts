@@ -0,0 +1,64 @@
+import * as anchor from "@coral-xyz/anchor";
+import BN from "bn.js";
+import assert from "assert";
+import * as web3 from "@solana/web3.js";
+import { program } from "../client/constants";
+import { findHostAccountAddress } from "@/pda";
+import { assertErrorAsync, createMember } from "@/testing/utils";
+const DECIMALS = 9;
+const AMOUNT = 100;
+const MATURATION_PERIOD = 2_629_800;
+
+describe("Reward preview", async () => {
+  // Configure the client to use the local cluster
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const memberWallet = new web3.Keypair();
+
+  const amount = new BN(AMOUNT * Math.pow(10, DECIMALS));
+
+  const host = findHostAccountAddress();
+
+  let subscription: web3.PublicKey;
+
+  const preview = (timeNow: BN | null) =>
+    program.methods
+      .previewRewards(timeNow)
+      .accounts({ subscription, host })
+      .view();
+
+  beforeAll(async () => {
+    subscription = await createMember(amount, memberWallet);
+  });
+
+  it("reports the pending deposit maturity", async () => {
+    const { totalMatured, totalReleased, timeNextMaturity, tier } =
+      await preview(null);
+    const { slots } = await program.account.subscriptionAccount.fetch(
+      subscription
+    );
+
+    assert(totalMatured.eq(new BN(0)));
+    assert(totalReleased.eq(new BN(0)));
+    assert(timeNextMaturity.eq(slots[0].deposit.timeMatured));
+    // Too few tokens locked for a tier
+    assert(tier === 0);
+  });
+
+  it("projects rewards at a future date", async () => {
+    const current = await preview(null);
+    const projected = await preview(
+      new BN(Math.floor(Date.now() / 1000) + 2 * MATURATION_PERIOD)
+    );
+
+    // The deposit matured and accrued rewards by then
+    assert(projected.totalMatured.eq(amount));
+    assert(projected.timeNextMaturity.eq(new BN(0)));
+    assert(projected.pendingRewards.gt(current.pendingRewards));
+    assert(projected.claimableRewards.lte(projected.pendingRewards));
+  });
+
+  it("fails for a date in the past", async () => {
+    await assertErrorAsync(preview(new BN(1)), "Invalid timestamp");
+  });
+});
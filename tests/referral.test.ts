@@ -0,0 +1,179 @@
+import * as anchor from "@coral-xyz/anchor";
+import BN from "bn.js";
+import assert from "assert";
+import spl from "@solana/spl-token";
+import * as web3 from "@solana/web3.js";
+import { MINT_ADDRESS, program } from "../client/constants";
+import {
+  findHostAccountAddress,
+  findSubscriptionAccountAddress,
+  findVaultAccountAddress,
+} from "@/pda";
+import {
+  adminWallet,
+  assertErrorAsync,
+  claimAccounts,
+  confirm,
+  createMember,
+  hostParams,
+  mintTokenToAccount,
+} from "@/testing/utils";
+const DECIMALS = 9;
+const AMOUNT = 100;
+const ACTIVATION_REWARD = 10;
+const REFERRAL_BPS = 5_000;
+
+describe("Referral program", async () => {
+  // Configure the client to use the local cluster
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const memberWallet = new web3.Keypair(),
+    referrerWallet = new web3.Keypair();
+
+  const amount = new BN(AMOUNT * Math.pow(10, DECIMALS));
+
+  const host = findHostAccountAddress(),
+    subscription = findSubscriptionAccountAddress(memberWallet);
+
+  let referrer: web3.PublicKey;
+
+  const configure = (params: object) =>
+    program.methods
+      .configureHost(hostParams(params))
+      .accounts({ host, admin: adminWallet.publicKey })
+      .signers([adminWallet])
+      .rpc();
+
+  const initialize = (
+    wallet: web3.Keypair,
+    referrer: web3.PublicKey | null
+  ) =>
+    program.methods
+      .initialize()
+      .accounts({
+        subscription: findSubscriptionAccountAddress(wallet),
+        host,
+        referrer,
+        signer: wallet.publicKey,
+        systemProgram: web3.SystemProgram.programId,
+      })
+      .signers([wallet])
+      .rpc();
+
+  beforeAll(async () => {
+    await configure({
+      activationReward: new BN(ACTIVATION_REWARD),
+      referralBps: new BN(REFERRAL_BPS),
+    }).then(confirm);
+    referrer = await createMember(amount, referrerWallet);
+    await mintTokenToAccount(amount, memberWallet.publicKey, adminWallet);
+  });
+
+  describe("Initialize", () => {
+    it("fails for a referrer that isn't active", async () => {
+      // Initialized without the first deposit activating it
+      const pendingWallet = new web3.Keypair();
+      await mintTokenToAccount(amount, pendingWallet.publicKey, adminWallet);
+      await initialize(pendingWallet, null).then(confirm);
+
+      await assertErrorAsync(
+        initialize(
+          memberWallet,
+          findSubscriptionAccountAddress(pendingWallet)
+        ),
+        "Referrer does not match the registered referrer"
+      );
+    });
+
+    it("records an active referrer on the new subscription", async () => {
+      const { totalReferrals } =
+        await program.account.subscriptionAccount.fetch(referrer);
+      await initialize(memberWallet, referrer).then(confirm);
+
+      const account =
+        await program.account.subscriptionAccount.fetch(subscription);
+      assert(account.referrer.equals(referrer));
+      const referrerAccount =
+        await program.account.subscriptionAccount.fetch(referrer);
+      assert(referrerAccount.totalReferrals.eq(totalReferrals.add(new BN(1))));
+
+      // The first deposit activates the member with the activation reward
+      await program.methods
+        .deposit(amount, null)
+        .accounts({
+          subscription,
+          host,
+          vaultTokenAccount: findVaultAccountAddress(
+            MINT_ADDRESS,
+            memberWallet
+          ),
+          sourceTokenAccount: spl.getAssociatedTokenAddressSync(
+            MINT_ADDRESS,
+            memberWallet.publicKey
+          ),
+          mint: MINT_ADDRESS,
+          signer: memberWallet.publicKey,
+          tokenProgram: spl.TOKEN_PROGRAM_ID,
+          systemProgram: web3.SystemProgram.programId,
+        })
+        .signers([memberWallet])
+        .rpc()
+        .then(confirm);
+    });
+  });
+
+  describe("Claim", () => {
+    it("fails for a referrer the member wasn't referred by", async () => {
+      const wallet = new web3.Keypair();
+      await createMember(amount, wallet);
+      await assertErrorAsync(
+        program.methods
+          .claim(null)
+          .accounts({
+            ...claimAccounts(wallet, wallet.publicKey),
+            referrer,
+          })
+          .signers([wallet])
+          .rpc(),
+        "Referrer does not match the registered referrer"
+      );
+    });
+
+    it("fails to claim without the registered referrer", async () => {
+      await assertErrorAsync(
+        program.methods
+          .claim(null)
+          .accounts(claimAccounts(memberWallet, memberWallet.publicKey))
+          .signers([memberWallet])
+          .rpc(),
+        "Referrer does not match the registered referrer"
+      );
+    });
+
+    it("credits the referrer a share of the claimed rewards", async () => {
+      const before = await program.account.subscriptionAccount.fetch(referrer);
+      const { totalRewards } =
+        await program.account.subscriptionAccount.fetch(subscription);
+      await program.methods
+        .claim(null)
+        .accounts({
+          ...claimAccounts(memberWallet, memberWallet.publicKey),
+          referrer,
+        })
+        .signers([memberWallet])
+        .rpc()
+        .then(confirm);
+
+      const share = totalRewards
+        .mul(new BN(REFERRAL_BPS))
+        .div(new BN(10_000));
+      const after = await program.account.subscriptionAccount.fetch(referrer);
+      assert(after.referralRewards.eq(before.referralRewards.add(share)));
+      assert(after.totalRewards.eq(before.totalRewards.add(share)));
+    });
+  });
+
+  afterAll(async () => {
+    await configure({}).then(confirm);
+  });
+});
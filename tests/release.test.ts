@@ -0,0 +1,105 @@
+import * as anchor from "@coral-xyz/anchor";
+import BN from "bn.js";
+import assert from "assert";
+import * as web3 from "@solana/web3.js";
+import spl from "@solana/spl-token";
+import { MINT_ADDRESS, program } from "../client/constants";
+import { findHostAccountAddress, findVaultAccountAddress } from "@/pda";
+import { assertErrorAsync, createMember } from "@/testing/utils";
+const DECIMALS = 9;
+const AMOUNT = 100;
+
+describe("Delayed large releases", async () => {
+  // Configure the client to use the local cluster
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const memberWallet = new web3.Keypair();
+
+  const amount = new BN(AMOUNT * Math.pow(10, DECIMALS));
+
+  let subscription: web3.PublicKey;
+
+  beforeAll(async () => {
+    subscription = await createMember(amount, memberWallet);
+  });
+
+  describe("Release", () => {
+    it("fails before the deposit has matured", async () => {
+      await assertErrorAsync(
+        program.methods
+          .release(amount, null)
+          .accounts({
+            subscription,
+            host: findHostAccountAddress(),
+            vaultTokenAccount: findVaultAccountAddress(
+              MINT_ADDRESS,
+              memberWallet
+            ),
+            sourceTokenAccount: spl.getAssociatedTokenAddressSync(
+              MINT_ADDRESS,
+              memberWallet.publicKey
+            ),
+            mint: MINT_ADDRESS,
+            signer: memberWallet.publicKey,
+            tokenProgram: spl.TOKEN_PROGRAM_ID,
+            systemProgram: web3.SystemProgram.programId,
+          })
+          .signers([memberWallet])
+          .rpc(),
+        "Insufficient token balance"
+      );
+
+      // The deposit remains locked in its pending slot
+      const account =
+        await program.account.subscriptionAccount.fetch(subscription);
+      assert(account.totalAmount.eq(amount));
+      assert(account.totalMatured.eq(new BN(0)));
+      assert(account.slots.length === 1);
+      assert("deposit" in account.slots[0]);
+    });
+
+    it.skip("extends the unbonding period above the large release threshold");
+
+    it.skip("re-locks releases left uncollected past the collection window");
+  });
+
+  describe("Cancel release", () => {
+    it("fails for a deposit slot", async () => {
+      await assertErrorAsync(
+        program.methods
+          .cancelRelease(0, null)
+          .accounts({
+            subscription,
+            host: findHostAccountAddress(),
+            signer: memberWallet.publicKey,
+            systemProgram: web3.SystemProgram.programId,
+          })
+          .signers([memberWallet])
+          .rpc(),
+        "Release can't be cancelled"
+      );
+    });
+
+    it("fails for a missing slot", async () => {
+      await assertErrorAsync(
+        program.methods
+          .cancelRelease(9, null)
+          .accounts({
+            subscription,
+            host: findHostAccountAddress(),
+            signer: memberWallet.publicKey,
+            systemProgram: web3.SystemProgram.programId,
+          })
+          .signers([memberWallet])
+          .rpc(),
+        "Slot index out of bounds"
+      );
+
+      const account =
+        await program.account.subscriptionAccount.fetch(subscription);
+      assert(account.slots.length === 1);
+    });
+
+    it.skip("returns a delayed release to the matured balance");
+  });
+});
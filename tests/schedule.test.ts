@@ -0,0 +1,104 @@
+import * as anchor from "@coral-xyz/anchor";
+import BN from "bn.js";
+import assert from "assert";
+import { program } from "../client/constants";
+import { findHostAccountAddress } from "@/pda";
+import { adminWallet, assertErrorAsync, confirm } from "@/testing/utils";
+const MAX_MULTIPLIER_BPS = 100_000;
+const MAX_SCHEDULE_STEPS = 8;
+
+const emissionSchedule = (schedule: object = {}) => ({
+  kind: 0,
+  timeStart: new BN(0),
+  decayBps: new BN(0),
+  steps: Array(MAX_SCHEDULE_STEPS).fill({
+    timeStart: new BN(0),
+    multiplierBps: new BN(0),
+  }),
+  ...schedule,
+});
+
+const steps = (...values: [number, number][]) =>
+  emissionSchedule().steps.map((step: object, index: number) =>
+    index < values.length
+      ? {
+          timeStart: new BN(values[index][0]),
+          multiplierBps: new BN(values[index][1]),
+        }
+      : step
+  );
+
+describe("Emission schedule", async () => {
+  // Configure the client to use the local cluster
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const host = findHostAccountAddress();
+
+  const setSchedule = (schedule: object) =>
+    program.methods
+      .setEmissionSchedule(emissionSchedule(schedule))
+      .accounts({ host, admin: adminWallet.publicKey })
+      .signers([adminWallet])
+      .rpc();
+
+  it("fails for an unknown schedule kind", async () => {
+    await assertErrorAsync(
+      setSchedule({ kind: 7 }),
+      "Invalid emission schedule"
+    );
+  });
+
+  it("fails for unsorted steps", async () => {
+    await assertErrorAsync(
+      setSchedule({ kind: 1, steps: steps([200, 5_000], [100, 2_500]) }),
+      "Invalid emission schedule"
+    );
+  });
+
+  it("fails for step multipliers above the maximum", async () => {
+    await assertErrorAsync(
+      setSchedule({ kind: 1, steps: steps([100, MAX_MULTIPLIER_BPS + 1]) }),
+      "Reward multiplier exceeds the maximum"
+    );
+  });
+
+  it("fails for a decay outside the basis points range", async () => {
+    await assertErrorAsync(
+      setSchedule({ kind: 2, decayBps: new BN(10_000) }),
+      "Invalid emission schedule"
+    );
+  });
+
+  it("applies a halving schedule", async () => {
+    await setSchedule({
+      kind: 1,
+      steps: steps([100, 10_000], [200, 5_000]),
+    }).then(confirm);
+
+    const { schedule } = await program.account.hostAccount.fetch(host);
+    assert(schedule.kind === 1);
+    assert(schedule.steps[0].timeStart.eq(new BN(100)));
+    assert(schedule.steps[0].multiplierBps.eq(new BN(10_000)));
+    assert(schedule.steps[1].timeStart.eq(new BN(200)));
+    assert(schedule.steps[1].multiplierBps.eq(new BN(5_000)));
+    // Unused steps stay empty
+    assert(schedule.steps[2].timeStart.eq(new BN(0)));
+  });
+
+  it("applies a decaying schedule", async () => {
+    await setSchedule({
+      kind: 2,
+      timeStart: new BN(100),
+      decayBps: new BN(1_000),
+    }).then(confirm);
+
+    const { schedule } = await program.account.hostAccount.fetch(host);
+    assert(schedule.kind === 2);
+    assert(schedule.timeStart.eq(new BN(100)));
+    assert(schedule.decayBps.eq(new BN(1_000)));
+  });
+
+  afterAll(async () => {
+    await setSchedule({}).then(confirm);
+  });
+});
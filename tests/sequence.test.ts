@@ -0,0 +1,54 @@
+import * as anchor from "@coral-xyz/anchor";
+import BN from "bn.js";
+import assert from "assert";
+import * as web3 from "@solana/web3.js";
+import { program } from "../client/constants";
+import { assertErrorAsync, confirm, createMember } from "@/testing/utils";
+const DECIMALS = 9;
+const AMOUNT = 100;
+
+describe("Subscription sequence number", async () => {
+  // Configure the client to use the local cluster
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const memberWallet = new web3.Keypair(),
+    delegateWallet = new web3.Keypair();
+
+  const amount = new BN(AMOUNT * Math.pow(10, DECIMALS));
+
+  let subscription: web3.PublicKey;
+
+  beforeAll(async () => {
+    subscription = await createMember(amount, memberWallet);
+  });
+
+  it("increments on every mutation", async () => {
+    const { sequence } =
+      await program.account.subscriptionAccount.fetch(subscription);
+
+    await program.methods
+      .setClaimDelegate(delegateWallet.publicKey, false, sequence)
+      .accounts({ subscription, signer: memberWallet.publicKey })
+      .signers([memberWallet])
+      .rpc()
+      .then(confirm);
+
+    const account =
+      await program.account.subscriptionAccount.fetch(subscription);
+    assert(account.sequence.eq(sequence.add(new BN(1))));
+  });
+
+  it("fails when the expected sequence is stale", async () => {
+    const { sequence } =
+      await program.account.subscriptionAccount.fetch(subscription);
+
+    await assertErrorAsync(
+      program.methods
+        .setClaimDelegate(null, false, sequence.sub(new BN(1)))
+        .accounts({ subscription, signer: memberWallet.publicKey })
+        .signers([memberWallet])
+        .rpc(),
+      "Account changed since the expected sequence"
+    );
+  });
+});
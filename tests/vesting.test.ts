@@ -0,0 +1,79 @@
+import * as anchor from "@coral-xyz/anchor";
+import BN from "bn.js";
+import * as web3 from "@solana/web3.js";
+import { program } from "../client/constants";
+import { findHostAccountAddress } from "@/pda";
+import {
+  adminWallet,
+  assertErrorAsync,
+  claimAccounts,
+  confirm,
+  createMember,
+  hostParams,
+} from "@/testing/utils";
+const DECIMALS = 9;
+const AMOUNT = 100;
+const VESTING_PERIOD = 86_400;
+
+describe("Linear vesting of claimed rewards", async () => {
+  // Configure the client to use the local cluster
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const memberWallet = new web3.Keypair();
+
+  const amount = new BN(AMOUNT * Math.pow(10, DECIMALS));
+
+  const host = findHostAccountAddress();
+
+  const configure = (params: object) =>
+    program.methods
+      .configureHost(hostParams(params))
+      .accounts({ host, admin: adminWallet.publicKey })
+      .signers([adminWallet])
+      .rpc();
+
+  beforeAll(async () => {
+    await createMember(amount, memberWallet);
+    // Allow anyone to claim, so only vesting restricts the signer
+    await program.methods
+      .setClaimDelegate(null, true, null)
+      .accounts({
+        subscription: claimAccounts(memberWallet, memberWallet.publicKey)
+          .subscription,
+        signer: memberWallet.publicKey,
+      })
+      .signers([memberWallet])
+      .rpc()
+      .then(confirm);
+    await configure({ vestingPeriod: new BN(VESTING_PERIOD) }).then(confirm);
+  });
+
+  it("fails to claim for the member while vesting", async () => {
+    // Funded keeper paying for the reward token account
+    await assertErrorAsync(
+      program.methods
+        .claim(null)
+        .accounts(claimAccounts(memberWallet, adminWallet.publicKey))
+        .signers([adminWallet])
+        .rpc(),
+      "Signer is not authorized to claim for the member"
+    );
+  });
+
+  it("fails to collect before any rewards vested", async () => {
+    await assertErrorAsync(
+      program.methods
+        .collectVested(null)
+        .accounts(claimAccounts(memberWallet, memberWallet.publicKey))
+        .signers([memberWallet])
+        .rpc(),
+      "No rewards available"
+    );
+  });
+
+  it.skip("releases claimed rewards linearly over the vesting period");
+
+  afterAll(async () => {
+    await configure({}).then(confirm);
+  });
+});
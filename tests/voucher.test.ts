@@ -0,0 +1,147 @@
+import * as anchor from "@coral-xyz/anchor";
+import BN from "bn.js";
+import assert from "assert";
+import * as web3 from "@solana/web3.js";
+import { program } from "../client/constants";
+import {
+  findHostAccountAddress,
+  findSubscriptionAccountAddress,
+  findVoucherAccountAddress,
+} from "@/pda";
+import {
+  adminWallet,
+  assertErrorAsync,
+  confirm,
+  createMember,
+  hostParams,
+} from "@/testing/utils";
+const DECIMALS = 9;
+const AMOUNT = 100;
+const VOUCHER_DOMAIN = "lfv-voucher";
+
+describe("Reward vouchers", async () => {
+  // Configure the client to use the local cluster
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const memberWallet = new web3.Keypair(),
+    voucherWallet = new web3.Keypair();
+
+  const amount = new BN(AMOUNT * Math.pow(10, DECIMALS));
+
+  const host = findHostAccountAddress();
+
+  const configure = (params: object) =>
+    program.methods
+      .configureHost(hostParams(params))
+      .accounts({ host, admin: adminWallet.publicKey })
+      .signers([adminWallet])
+      .rpc();
+
+  // Message signed by the backend, matching `VoucherAccount::message`
+  const voucherMessage = (reward: BN, nonce: BN, timeExpiry: BN) =>
+    Buffer.concat([
+      Buffer.from(VOUCHER_DOMAIN),
+      program.programId.toBuffer(),
+      host.toBuffer(),
+      memberWallet.publicKey.toBuffer(),
+      reward.toArrayLike(Buffer, "le", 8),
+      nonce.toArrayLike(Buffer, "le", 8),
+      timeExpiry.toArrayLike(Buffer, "le", 8),
+    ]);
+
+  const redeemVoucher = (
+    reward: BN,
+    nonce: BN,
+    timeExpiry: BN,
+    preInstructions: web3.TransactionInstruction[] = []
+  ) =>
+    program.methods
+      .redeemVoucher(reward, nonce, timeExpiry, null)
+      .accounts({
+        subscription: findSubscriptionAccountAddress(memberWallet),
+        host,
+        voucher: findVoucherAccountAddress(memberWallet.publicKey, nonce),
+        instructions: web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+        signer: memberWallet.publicKey,
+        systemProgram: web3.SystemProgram.programId,
+      })
+      .preInstructions(preInstructions)
+      .signers([memberWallet])
+      .rpc();
+
+  const signVoucher = (reward: BN, nonce: BN, timeExpiry: BN) =>
+    web3.Ed25519Program.createInstructionWithPrivateKey({
+      privateKey: voucherWallet.secretKey,
+      message: voucherMessage(reward, nonce, timeExpiry),
+    });
+
+  const timeExpiry = () => new BN(Math.floor(Date.now() / 1000) + 3600);
+
+  beforeAll(async () => {
+    await createMember(amount, memberWallet);
+  });
+
+  describe("Redeem", () => {
+    it("fails while vouchers are disabled", async () => {
+      await assertErrorAsync(
+        redeemVoucher(new BN(AMOUNT), new BN(0), timeExpiry()),
+        "Vouchers are not enabled"
+      );
+    });
+
+    it("fails for an expired voucher", async () => {
+      await configure({ voucherSigner: voucherWallet.publicKey }).then(
+        confirm
+      );
+      await assertErrorAsync(
+        redeemVoucher(new BN(AMOUNT), new BN(1), new BN(1)),
+        "Voucher has expired"
+      );
+    });
+
+    it("fails without the Ed25519 verification", async () => {
+      await assertErrorAsync(
+        redeemVoucher(new BN(AMOUNT), new BN(2), timeExpiry()),
+        "Invalid voucher signature"
+      );
+    });
+
+    it("fails for a voucher signed for another amount", async () => {
+      const expiry = timeExpiry();
+      await assertErrorAsync(
+        redeemVoucher(new BN(AMOUNT), new BN(3), expiry, [
+          signVoucher(new BN(2 * AMOUNT), new BN(3), expiry),
+        ]),
+        "Invalid voucher signature"
+      );
+    });
+
+    it("credits the signed reward and records the nonce", async () => {
+      const expiry = timeExpiry(),
+        nonce = new BN(4),
+        subscription = findSubscriptionAccountAddress(memberWallet);
+      const before =
+        await program.account.subscriptionAccount.fetch(subscription);
+      await redeemVoucher(new BN(AMOUNT), nonce, expiry, [
+        signVoucher(new BN(AMOUNT), nonce, expiry),
+      ]).then(confirm);
+
+      const after =
+        await program.account.subscriptionAccount.fetch(subscription);
+      assert(after.totalRewards.eq(before.totalRewards.add(new BN(AMOUNT))));
+      assert(after.sequence.eq(before.sequence.add(new BN(1))));
+
+      const voucher = await program.account.voucherAccount.fetch(
+        findVoucherAccountAddress(memberWallet.publicKey, nonce)
+      );
+      assert(voucher.nonce.eq(nonce));
+      assert(voucher.member.equals(memberWallet.publicKey));
+      assert(voucher.timeRedeemed.lt(expiry));
+      assert(voucher.timeRedeemed.gt(new BN(0)));
+    });
+  });
+
+  afterAll(async () => {
+    await configure({}).then(confirm);
+  });
+});
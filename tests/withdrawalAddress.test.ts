@@ -0,0 +1,121 @@
+import * as anchor from "@coral-xyz/anchor";
+import BN from "bn.js";
+import assert from "assert";
+import * as web3 from "@solana/web3.js";
+import spl from "@solana/spl-token";
+import { MINT_ADDRESS, program } from "../client/constants";
+import { findHostAccountAddress, findVaultAccountAddress } from "@/pda";
+import {
+  adminWallet,
+  assertErrorAsync,
+  confirm,
+  createMember,
+} from "@/testing/utils";
+const DECIMALS = 9;
+const AMOUNT = 100;
+const WITHDRAWAL_ADDRESS_DELAY = 259_200;
+
+describe("Registered withdrawal address", async () => {
+  // Configure the client to use the local cluster
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const memberWallet = new web3.Keypair(),
+    destinationWallet = new web3.Keypair();
+
+  const amount = new BN(AMOUNT * Math.pow(10, DECIMALS));
+
+  let subscription: web3.PublicKey, destinationTokenAccount: web3.PublicKey;
+
+  beforeAll(async () => {
+    subscription = await createMember(amount, memberWallet);
+    destinationTokenAccount = await spl.createAssociatedTokenAccount(
+      program.provider.connection,
+      adminWallet,
+      MINT_ADDRESS,
+      destinationWallet.publicKey
+    );
+  });
+
+  describe("Request a withdrawal address", () => {
+    it("records the pending destination", async () => {
+      const timeNow = Math.floor(Date.now() / 1000);
+      await program.methods
+        .setWithdrawalAddress(destinationWallet.publicKey, null)
+        .accounts({ subscription, signer: memberWallet.publicKey })
+        .signers([memberWallet])
+        .rpc()
+        .then(confirm);
+
+      const account =
+        await program.account.subscriptionAccount.fetch(subscription);
+      assert(
+        account.withdrawalAddress.pending.equals(destinationWallet.publicKey)
+      );
+      // Effective one delay after the request
+      assert(
+        account.withdrawalAddress.timeEffective.gte(
+          new BN(timeNow + WITHDRAWAL_ADDRESS_DELAY - 60)
+        )
+      );
+      assert(account.withdrawalAddress.address.equals(web3.PublicKey.default));
+    });
+
+    it("fails to withdraw to the pending destination", async () => {
+      await assertErrorAsync(
+        program.methods
+          .withdraw(null)
+          .accounts({
+            subscription,
+            host: findHostAccountAddress(),
+            vaultTokenAccount: findVaultAccountAddress(
+              MINT_ADDRESS,
+              memberWallet
+            ),
+            destinationTokenAccount,
+            mint: MINT_ADDRESS,
+            signer: memberWallet.publicKey,
+            tokenProgram: spl.TOKEN_PROGRAM_ID,
+            systemProgram: web3.SystemProgram.programId,
+          })
+          .signers([memberWallet])
+          .rpc(),
+        "Destination is not the registered withdrawal address"
+      );
+
+      const destination = await spl.getAccount(
+        program.provider.connection,
+        destinationTokenAccount
+      );
+      assert(destination.amount === BigInt(0));
+    });
+
+    it.skip("withdraws to the destination once the delay has elapsed");
+  });
+
+  describe("Migrate subscription", () => {
+    it("fails when already on the latest schema version", async () => {
+      const { totalLocked } = await program.account.hostAccount.fetch(
+        findHostAccountAddress()
+      );
+      await assertErrorAsync(
+        program.methods
+          .migrateSubscription()
+          .accounts({
+            subscription,
+            host: findHostAccountAddress(),
+            signer: memberWallet.publicKey,
+            systemProgram: web3.SystemProgram.programId,
+          })
+          .signers([memberWallet])
+          .rpc(),
+        "Account already uses the latest schema version"
+      );
+
+      // The matured balance isn't counted twice
+      const host = await program.account.hostAccount.fetch(
+        findHostAccountAddress()
+      );
+      assert(host.totalLocked.eq(totalLocked));
+    });
+  });
+});